@@ -0,0 +1,100 @@
+mod play;
+mod solve;
+
+use boggler_core::{
+  board::simple::EnglishAlphaSimpleBoard,
+  generate::{dice::DiceSet, rng::SplitMix64, seed::seeded_board},
+};
+use clap::Parser;
+use eyre::Result;
+use std::{
+  fs::File,
+  io::{BufRead, BufReader},
+};
+
+/// The longest word read from a dictionary.
+const MAX_WORD_LEN: usize = 50;
+
+#[derive(clap::Parser, Debug)]
+#[clap(author, version, about)]
+pub struct Args {
+  #[clap(subcommand)]
+  pub command: Command,
+}
+
+/// The arguments shared by every subcommand.
+#[derive(clap::Args, Debug)]
+pub struct CommonArgs {
+  /// The board code, the characters in the board row by row with multi-letter faces in
+  /// parentheses.
+  ///
+  /// Example: "modnstedetripyij"
+  ///
+  /// Default: Rolls the boggle dice.
+  #[clap(short, long)]
+  pub board: Option<String>,
+
+  /// A seed string to roll the same boggle dice as anyone else using the seed, such as
+  /// "daily-2024-01-31".
+  #[clap(short, long, conflicts_with = "board")]
+  pub seed: Option<String>,
+
+  /// The optional path to a text file containing words delimited by newline characters.
+  ///
+  /// Default: Uses dictionary baked into executable.
+  #[clap(short, long)]
+  pub dictionary: Option<String>,
+
+  /// The shortest word that counts, in letters.
+  #[clap(short, long, default_value = "3")]
+  pub min_word_len: usize,
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Command {
+  /// Plays a timed round on a board in the terminal.
+  Play(play::PlayArgs),
+  /// Finds every word on a board.
+  Solve(solve::SolveArgs),
+}
+
+fn main() -> Result<()> {
+  match Args::parse().command {
+    Command::Play(args) => play::run(args),
+    Command::Solve(args) => solve::run(args),
+  }
+}
+
+/// Reads a board from its code, or rolls the boggle dice with a seed string, or with a
+/// random seed if neither is supplied.
+fn get_board(
+  code: &Option<String>,
+  seed: &Option<String>,
+) -> Result<EnglishAlphaSimpleBoard> {
+  match (code, seed) {
+    (Some(code), _) => EnglishAlphaSimpleBoard::from_code(code),
+    (None, Some(seed)) => seeded_board(&DiceSet::new_boggle(), seed),
+    (None, None) => DiceSet::new_boggle().roll(&mut SplitMix64::from_entropy()),
+  }
+}
+
+/// Reads words from a dictionary file delimited by newline characters, or from the
+/// dictionary baked into the executable if no file is supplied.
+fn get_words(dictionary: &Option<String>) -> Result<Vec<String>> {
+  Ok(match dictionary {
+    // Read lines from dictionary file.
+    Some(dictionary) => BufReader::new(File::open(dictionary)?)
+      .lines()
+      .collect::<std::io::Result<Vec<_>>>()?
+      .into_iter()
+      .map(|word| word.trim().to_lowercase())
+      .filter(|word| !word.is_empty())
+      .collect(),
+    // Read lines from embedded dictionary.
+    None => include_str!(concat!(env!("OUT_DIR"), "/words.txt"))
+      .lines()
+      .map(|word| word.trim().to_lowercase())
+      .filter(|word| !word.is_empty())
+      .collect(),
+  })
+}
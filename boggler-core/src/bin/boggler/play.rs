@@ -0,0 +1,251 @@
+//! Timed interactive game.
+
+use crate::{get_board, get_words, CommonArgs, MAX_WORD_LEN};
+use boggler_core::{
+  board::{simple::EnglishAlphaSimpleBoard, Board, BoardCell},
+  charset::{english_alpha::EnglishAlpha, Charset},
+  game::{Outcome, Round, Submission},
+  scoring::{LengthTable, Scoring},
+  search::{find_words, options::SearchOptions},
+  trie::EnglishAlphaPrefixes,
+};
+use crossterm::{
+  cursor,
+  event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+  execute, queue,
+  style::Print,
+  terminal::{self, ClearType},
+};
+use eyre::Result;
+use std::{
+  io::{self, Write},
+  time::{Duration, Instant},
+};
+
+/// How often the countdown is redrawn while waiting for input.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(clap::Args, Debug)]
+pub struct PlayArgs {
+  #[clap(flatten)]
+  pub common: CommonArgs,
+
+  /// The length of the round in seconds.
+  #[clap(short, long, default_value = "180")]
+  pub time: u64,
+}
+
+/// Plays a round, then shows the score and the words that were missed.
+pub fn run(args: PlayArgs) -> Result<()> {
+  let board = get_board(&args.common.board, &args.common.seed)?;
+
+  let words = get_words(&args.common.dictionary)?;
+  let prefixes = EnglishAlphaPrefixes::from_words(&words, MAX_WORD_LEN);
+  let scoring = LengthTable::official();
+  let round =
+    Round::new(&board, &prefixes, scoring.clone()).with_min_len(args.common.min_word_len);
+
+  let mut stdout = io::stdout();
+
+  let guard = TerminalGuard::enter(&mut stdout)?;
+  let submitted = play(&mut stdout, &board, &round, Duration::from_secs(args.time));
+  drop(guard);
+
+  let submission = Submission {
+    player: String::new(),
+    words: submitted?,
+  };
+
+  let sheet = round.score(&[submission]).remove(0);
+  let found = sheet
+    .entries
+    .iter()
+    .filter_map(|entry| match entry.outcome {
+      Outcome::Scored { id, .. } => Some(id),
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+
+  let mut all = Vec::new();
+  let options = SearchOptions::new().with_min_len(args.common.min_word_len);
+  find_words(&board, &prefixes, &options, &mut all);
+  all.sort_unstable();
+  all.dedup();
+
+  let missed = all
+    .iter()
+    .filter(|id| !found.contains(id))
+    .filter_map(|id| prefixes.word(*id))
+    .collect::<Vec<_>>();
+
+  for line in board_lines(&board) {
+    println!("{line}");
+  }
+
+  println!();
+  println!("board: {}", board.code());
+  println!();
+  println!("found {}:", found.len());
+  for entry in &sheet.entries {
+    if let Outcome::Scored { points, .. } = entry.outcome {
+      println!("  {} ({points})", entry.word.trim().to_lowercase());
+    }
+  }
+
+  println!();
+  println!("missed {}: {}", missed.len(), missed.join(", "));
+  println!();
+  println!(
+    "score: {} of {}",
+    sheet.total,
+    scoring.score_words(&prefixes, &all).total
+  );
+
+  Ok(())
+}
+
+/// Keeps the terminal in raw mode on the alternate screen, restoring it when dropped.
+struct TerminalGuard;
+
+impl TerminalGuard {
+  /// Puts the terminal in raw mode on the alternate screen, restoring it if that fails
+  /// part way.
+  fn enter<W: Write>(out: &mut W) -> Result<Self> {
+    terminal::enable_raw_mode()?;
+    let guard = Self;
+
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    Ok(guard)
+  }
+}
+
+impl Drop for TerminalGuard {
+  fn drop(&mut self) {
+    let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+  }
+}
+
+/// Runs the countdown, reading words until the time is up or the player quits, and
+/// returns the accepted words.
+fn play<W: Write>(
+  out: &mut W,
+  board: &EnglishAlphaSimpleBoard,
+  round: &Round<'_, EnglishAlphaSimpleBoard, EnglishAlpha, (), LengthTable>,
+  duration: Duration,
+) -> Result<Vec<String>> {
+  let end = Instant::now() + duration;
+  let mut typed = String::new();
+  let mut feedback = String::new();
+  let mut accepted = Vec::new();
+  let mut ids = Vec::new();
+
+  loop {
+    let left = end.saturating_duration_since(Instant::now());
+    if left.is_zero() {
+      break;
+    }
+
+    render(out, board, left, &typed, &feedback, accepted.len())?;
+
+    if !event::poll(left.min(REDRAW_INTERVAL))? {
+      continue;
+    }
+
+    let KeyEvent { code, modifiers } = match event::read()? {
+      Event::Key(key) => key,
+      _ => continue,
+    };
+
+    match code {
+      KeyCode::Esc => break,
+      KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break,
+      KeyCode::Char(ch) => typed.push(ch),
+      KeyCode::Backspace => {
+        typed.pop();
+      }
+      KeyCode::Enter if !typed.trim().is_empty() => {
+        let word = std::mem::take(&mut typed);
+        feedback = match round.validate(&word) {
+          Ok(id) if ids.contains(&id) => format!("`{}` already found", word.trim()),
+          Ok(id) => {
+            ids.push(id);
+            accepted.push(word.clone());
+            format!("`{}` ok", word.trim())
+          }
+          Err(rejection) => format!("`{}` {rejection}", word.trim()),
+        };
+      }
+      _ => {}
+    }
+  }
+
+  Ok(accepted)
+}
+
+/// Draws the board, the time left, the last feedback and the word being typed.
+fn render<W: Write>(
+  out: &mut W,
+  board: &EnglishAlphaSimpleBoard,
+  left: Duration,
+  typed: &str,
+  feedback: &str,
+  found: usize,
+) -> Result<()> {
+  let seconds = left.as_secs_f32().ceil() as u64;
+
+  queue!(out, terminal::Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+
+  // Raw mode does not move the cursor to the start of the line on a new line.
+  for line in board_lines(board) {
+    queue!(out, Print(line), Print("\r\n"))?;
+  }
+
+  queue!(
+    out,
+    Print(format!(
+      "\r\n{}:{:02} left, {found} found\r\n",
+      seconds / 60,
+      seconds % 60
+    )),
+    Print(format!("{feedback}\r\n")),
+    Print(format!("> {typed}")),
+  )?;
+
+  out.flush()?;
+
+  Ok(())
+}
+
+/// Gets the rows of a board as capitalized faces, with `q` shown as `Qu`.
+fn board_lines(board: &EnglishAlphaSimpleBoard) -> Vec<String> {
+  (0..board.height() as u16)
+    .map(|y| {
+      (0..board.width() as u16)
+        .filter_map(|x| board.at(x, y))
+        .map(|cell| format!("{:<3}", face_label(cell.face())))
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+    })
+    .collect()
+}
+
+/// Gets a cell face as it is printed on a die, such as `A`, `Qu` or `Th`.
+fn face_label(face: &[u8]) -> String {
+  let mut label = face
+    .iter()
+    .filter_map(|ch| EnglishAlpha::from_prefix_char(*ch))
+    .map(|ch| match ch {
+      'q' => "qu".to_string(),
+      ch => ch.to_string(),
+    })
+    .collect::<String>();
+
+  if let Some(first) = label.get_mut(..1) {
+    first.make_ascii_uppercase();
+  }
+
+  label
+}
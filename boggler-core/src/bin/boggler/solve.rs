@@ -0,0 +1,231 @@
+//! Board solving with text or machine-readable output.
+
+use crate::{get_board, get_words, CommonArgs, MAX_WORD_LEN};
+use boggler_core::{
+  charset::english_alpha::EnglishAlpha,
+  scoring::{LengthTable, Scoring},
+  search::{find_words, options::SearchOptions, valid::find_word_paths, Path},
+  trie::EnglishAlphaPrefixes,
+};
+use crossterm::terminal;
+use eyre::Result;
+use serde::Serialize;
+use std::{
+  collections::BTreeMap,
+  io::{self, Write},
+  time::Instant,
+};
+
+#[derive(clap::Args, Debug)]
+pub struct SolveArgs {
+  #[clap(flatten)]
+  pub common: CommonArgs,
+
+  /// The output format.
+  #[clap(short, long, arg_enum, default_value = "text")]
+  pub format: Format,
+}
+
+/// How the words of a board are printed.
+#[derive(clap::ArgEnum, Clone, Copy, Debug)]
+pub enum Format {
+  /// A single JSON document with the summary and every word.
+  Json,
+  /// A header and a row for each word, with the summary printed to stderr.
+  Csv,
+  /// A JSON record for each word, then a summary record.
+  Ndjson,
+  /// A wrapped list of words and a count of words of each length.
+  Text,
+}
+
+/// A word found on a board.
+#[derive(Serialize, Debug)]
+pub struct WordReport {
+  /// The word as it is spelled in the dictionary.
+  pub word: String,
+  /// The number of letters in the word.
+  pub length: usize,
+  /// The points the word scores.
+  pub score: u32,
+  /// Every path that traces the word, as `[x, y]` cells.
+  pub paths: Vec<Path>,
+}
+
+/// The statistics of a solved board.
+#[derive(Serialize, Debug)]
+pub struct Summary {
+  /// The board code.
+  pub board: String,
+  /// The number of distinct words found.
+  pub words: usize,
+  /// The points of every word found.
+  pub score: u32,
+  /// The number of words of each length.
+  pub lengths: BTreeMap<usize, usize>,
+}
+
+/// The JSON output.
+#[derive(Serialize, Debug)]
+struct Document<'a> {
+  summary: &'a Summary,
+  words: &'a [WordReport],
+}
+
+/// A single line of NDJSON output.
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Record<'a> {
+  Word(&'a WordReport),
+  Summary(&'a Summary),
+}
+
+/// Finds every word on a board and prints them in the chosen format.
+pub fn run(args: SolveArgs) -> Result<()> {
+  let time = Instant::now();
+  let board = get_board(&args.common.board, &args.common.seed)?;
+
+  let words = get_words(&args.common.dictionary)?;
+  let prefixes = EnglishAlphaPrefixes::from_words(&words, MAX_WORD_LEN);
+  let scoring = LengthTable::official();
+
+  let mut found = Vec::new();
+  let options = SearchOptions::new().with_min_len(args.common.min_word_len);
+  find_words(&board, &prefixes, &options, &mut found);
+
+  let score = scoring.score_words(&prefixes, &found);
+  let mut reports = score
+    .words
+    .iter()
+    .filter_map(|&(id, score)| {
+      let word = prefixes.word(id)?;
+
+      Some(WordReport {
+        word: word.to_string(),
+        length: word.chars().count(),
+        score,
+        paths: find_word_paths::<EnglishAlpha, _>(&board, word),
+      })
+    })
+    .collect::<Vec<_>>();
+
+  reports.sort_unstable_by(|a, b| a.word.cmp(&b.word));
+
+  let mut lengths = BTreeMap::new();
+  for report in &reports {
+    *lengths.entry(report.length).or_default() += 1;
+  }
+
+  let summary = Summary {
+    board: board.code(),
+    words: reports.len(),
+    score: score.total,
+    lengths,
+  };
+
+  let mut out = io::stdout().lock();
+
+  match args.format {
+    Format::Json => {
+      let document = Document {
+        summary: &summary,
+        words: &reports,
+      };
+
+      serde_json::to_writer_pretty(&mut out, &document)?;
+      writeln!(out)?;
+    }
+    Format::Ndjson => {
+      for report in &reports {
+        serde_json::to_writer(&mut out, &Record::Word(report))?;
+        writeln!(out)?;
+      }
+
+      serde_json::to_writer(&mut out, &Record::Summary(&summary))?;
+      writeln!(out)?;
+    }
+    Format::Csv => {
+      writeln!(out, "word,length,score,paths")?;
+
+      for report in &reports {
+        writeln!(
+          out,
+          "{},{},{},{}",
+          csv_field(&report.word),
+          report.length,
+          report.score,
+          csv_field(&csv_paths(&report.paths))
+        )?;
+      }
+
+      print_summary(&mut io::stderr().lock(), &summary)?;
+    }
+    Format::Text => {
+      print_text(&mut out, &reports, &summary)?;
+      writeln!(out, "done in {:?}", time.elapsed())?;
+    }
+  }
+
+  Ok(())
+}
+
+/// Quotes a CSV field, doubling any quotes inside it.
+fn csv_field(field: &str) -> String {
+  format!("\"{}\"", field.replace('"', "\"\""))
+}
+
+/// Formats paths for a CSV cell, such as `0:0 1:0 1:1;0:0 0:1 1:1`, with cells as `x:y`
+/// separated by spaces and paths separated by semicolons.
+fn csv_paths(paths: &[Path]) -> String {
+  paths
+    .iter()
+    .map(|path| {
+      path
+        .iter()
+        .map(|(x, y)| format!("{x}:{y}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+    })
+    .collect::<Vec<_>>()
+    .join(";")
+}
+
+/// Prints the words as a comma separated list wrapped to the terminal width, followed by
+/// the summary.
+fn print_text<W: Write>(
+  out: &mut W,
+  reports: &[WordReport],
+  summary: &Summary,
+) -> Result<()> {
+  let width_max = terminal::size().map_or(80, |(width, _)| width as usize);
+  let mut width = 0;
+
+  for report in reports {
+    let word_width = report.word.len() + 2;
+    if word_width + width > width_max && width > 0 {
+      writeln!(out)?;
+      width = 0;
+    }
+
+    write!(out, "{}, ", report.word)?;
+    width += word_width;
+  }
+
+  writeln!(out)?;
+  writeln!(out)?;
+
+  print_summary(out, summary)
+}
+
+/// Prints the count of words of each length, the totals and the board code.
+fn print_summary<W: Write>(out: &mut W, summary: &Summary) -> Result<()> {
+  for (len, count) in &summary.lengths {
+    writeln!(out, "len {len}: {count}")?;
+  }
+
+  writeln!(out, "total: {}", summary.words)?;
+  writeln!(out, "score: {}", summary.score)?;
+  writeln!(out, "board: {}", summary.board)?;
+
+  Ok(())
+}
@@ -11,10 +11,21 @@ pub trait Board<'board> {
   /// Gets a board cell at a specific position.
   fn at(&self, x: u16, y: u16) -> Option<Self::Cell>;
 
-  /// Gets the board size.
-  ///
-  /// Because the board will always be square this corresponds to both width and height.
-  fn size(&self) -> usize;
+  /// Gets the number of columns.
+  fn width(&self) -> usize;
+
+  /// Gets the number of rows.
+  fn height(&self) -> usize;
+
+  /// Gets the number of cells.
+  fn cell_count(&self) -> usize {
+    self.width() * self.height()
+  }
+
+  /// Gets the position of a cell in the board cells laid out row by row.
+  fn index(&self, x: u16, y: u16) -> usize {
+    y as usize * self.width() + x as usize
+  }
 
   /// Gets a cell's neighbors.
   fn neighbors(&'board self, x: u16, y: u16) -> Self::Neighbors;
@@ -30,8 +41,15 @@ pub trait BoardCell {
 
   /// Gets the ascii representation of the cell character.
   ///
-  /// In boggle the `q` character character is implicitly both `q` and `u`.
+  /// In boggle the `q` character character is implicitly both `q` and `u`. For cells
+  /// with several characters this is the first one.
   fn ch(&self) -> u8;
+
+  /// Gets every character on the cell, in order.
+  ///
+  /// Usually only [BoardCell::ch], but dice such as big boggle's have multi-letter faces
+  /// like `Th` that must be used whole.
+  fn face(&self) -> &[u8];
 }
 
 /// Determines if a one dimensional array length can be viewed as a two dimensional array
@@ -2,17 +2,60 @@
 
 use crate::board::BoardCell;
 
+/// The most charset characters on a single cell face.
+pub const MAX_FACE_LEN: usize = 4;
+
+/// The charset characters on a cell face, such as `e`, or `t` and `h` for a `Th` face.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Face {
+  chars: [u8; MAX_FACE_LEN],
+  len: u8,
+}
+
+impl Face {
+  /// Create a [Face] from charset characters, or `None` if there are none or more than
+  /// [MAX_FACE_LEN].
+  pub fn new(chars: &[u8]) -> Option<Self> {
+    if chars.is_empty() || chars.len() > MAX_FACE_LEN {
+      return None;
+    }
+
+    let mut face = Self {
+      chars: [0; MAX_FACE_LEN],
+      len: chars.len() as u8,
+    };
+
+    face.chars[..chars.len()].copy_from_slice(chars);
+
+    Some(face)
+  }
+
+  /// Gets the charset characters.
+  pub fn as_slice(&self) -> &[u8] {
+    &self.chars[..self.len as usize]
+  }
+}
+
+impl From<u8> for Face {
+  fn from(ch: u8) -> Self {
+    Self {
+      chars: [ch, 0, 0, 0],
+      len: 1,
+    }
+  }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SimpleBoardCell {
   x: u16,
   y: u16,
-  ch: u8,
+  face: Face,
 }
 
 impl SimpleBoardCell {
   /// Create a [SimpleBoardCell].
-  pub fn new(x: u16, y: u16, ch: u8) -> Self {
-    Self { x, y, ch }
+  pub fn new(x: u16, y: u16, face: Face) -> Self {
+    Self { x, y, face }
   }
 }
 
@@ -26,6 +69,10 @@ impl BoardCell for SimpleBoardCell {
   }
 
   fn ch(&self) -> u8 {
-    self.ch
+    self.face.chars[0]
+  }
+
+  fn face(&self) -> &[u8] {
+    self.face.as_slice()
   }
 }
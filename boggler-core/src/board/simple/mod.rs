@@ -3,7 +3,10 @@
 pub mod cell;
 pub mod neighbors;
 
-use self::{cell::SimpleBoardCell, neighbors::SimpleNeighbors};
+use self::{
+  cell::{Face, SimpleBoardCell},
+  neighbors::SimpleNeighbors,
+};
 use super::Board;
 use crate::charset::{english_alpha::EnglishAlpha, Charset};
 use eyre::Result;
@@ -15,32 +18,150 @@ pub type EnglishAlphaSimpleBoard = SimpleBoard<EnglishAlpha>;
 /// Simple boggle board.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SimpleBoard<C: Charset> {
-  buf: Vec<u8>,
+  buf: Vec<Face>,
+  width: usize,
   _phantom: PhantomData<C>,
 }
 
 impl<C: Charset> SimpleBoard<C> {
-  /// Create a [SimpleBoard] from a string.
+  /// Create a square [SimpleBoard] from a string with a character on each cell, row by
+  /// row.
   pub fn new(board: &str) -> Result<Self> {
-    let board = Self {
-      buf: board
-        .chars()
-        .map(|ch| {
-          C::to_prefix_char(ch)
-            .ok_or(eyre::eyre!("board contains invalid character `{ch}`"))
-        })
-        .collect::<Result<Vec<_>>>()?,
-      _phantom: Default::default(),
+    let buf = board
+      .chars()
+      .map(|ch| {
+        C::to_prefix_char(ch)
+          .map(Face::from)
+          .ok_or(eyre::eyre!("board contains invalid character `{ch}`"))
+      })
+      .collect::<Result<Vec<_>>>()?;
+
+    Self::from_square_buf(buf)
+  }
+
+  /// Create a square [SimpleBoard] from the face of each cell, row by row.
+  ///
+  /// Faces are trimmed like dictionary words, so an english `Qu` face is the single
+  /// character `q` while `Th` is two characters.
+  pub fn from_faces<F: AsRef<str>>(faces: &[F]) -> Result<Self> {
+    Self::from_square_buf(Self::parse_faces(faces)?)
+  }
+
+  /// Create a [SimpleBoard] with `width` columns from the face of each cell, row by row.
+  ///
+  /// See [SimpleBoard::from_faces] for how faces are read.
+  pub fn from_faces_with_width<F: AsRef<str>>(faces: &[F], width: usize) -> Result<Self> {
+    Self::from_buf(Self::parse_faces(faces)?, width)
+  }
+
+  fn parse_faces<F: AsRef<str>>(faces: &[F]) -> Result<Vec<Face>> {
+    faces
+      .iter()
+      .map(|face| {
+        let face = face.as_ref();
+
+        C::to_prefix_chars(face)
+          .and_then(|chars| Face::new(&chars))
+          .ok_or(eyre::eyre!("board contains invalid face `{face}`"))
+      })
+      .collect()
+  }
+
+  /// Create a [SimpleBoard] from a code made by [SimpleBoard::code].
+  pub fn from_code(code: &str) -> Result<Self> {
+    let (width, code) = match code.split_once(':') {
+      Some((width, code)) => {
+        let width = width
+          .parse::<usize>()
+          .map_err(|err| eyre::eyre!("invalid board code width `{width}`: {err}"))?;
+
+        (Some(width), code)
+      }
+      None => (None, code),
     };
 
-    if (u16::MAX as usize) < board.size() {
-      eyre::bail!("number type `N` is not large enough to represent the board size");
+    let mut faces = Vec::new();
+    let mut chars = code.chars();
+
+    while let Some(ch) = chars.next() {
+      if ch != '(' {
+        faces.push(ch.to_string());
+        continue;
+      }
+
+      let mut face = String::new();
+      loop {
+        match chars.next() {
+          Some(')') => break,
+          Some(ch) => face.push(ch),
+          None => eyre::bail!("board code has an unclosed face `({face}`"),
+        }
+      }
+
+      faces.push(face);
+    }
+
+    match width {
+      Some(width) => Self::from_faces_with_width(&faces, width),
+      None => Self::from_faces(&faces),
+    }
+  }
+
+  /// Gets a compact code that [SimpleBoard::from_code] turns back into the board.
+  ///
+  /// The code is the characters of each cell, row by row, with faces of several
+  /// characters in parentheses, such as `(th)eax`. Boards that are not square start
+  /// with the width, such as `3:abcdef`.
+  pub fn code(&self) -> String {
+    let mut code = String::with_capacity(self.buf.len());
+    if self.width != self.height() {
+      code.push_str(&format!("{}:", self.width));
     }
 
-    if !super::is_1d_len_square_in_2d(board.buf.len()) {
+    for face in &self.buf {
+      let chars = face
+        .as_slice()
+        .iter()
+        .filter_map(|ch| C::from_prefix_char(*ch));
+
+      match face.as_slice().len() {
+        1 => code.extend(chars),
+        _ => {
+          code.push('(');
+          code.extend(chars);
+          code.push(')');
+        }
+      }
+    }
+
+    code
+  }
+
+  fn from_square_buf(buf: Vec<Face>) -> Result<Self> {
+    if !super::is_1d_len_square_in_2d(buf.len()) {
       eyre::bail!("expected square board");
     }
 
+    let width = (buf.len() as f64).sqrt() as usize;
+
+    Self::from_buf(buf, width)
+  }
+
+  fn from_buf(buf: Vec<Face>, width: usize) -> Result<Self> {
+    if buf.is_empty() || width == 0 || !buf.len().is_multiple_of(width) {
+      eyre::bail!("expected {width} cells in every row of the board");
+    }
+
+    let board = Self {
+      buf,
+      width,
+      _phantom: Default::default(),
+    };
+
+    if (u16::MAX as usize) < board.width.max(board.height()) {
+      eyre::bail!("number type `N` is not large enough to represent the board size");
+    }
+
     Ok(board)
   }
 }
@@ -50,20 +171,80 @@ impl<'board, C: Charset + 'board> Board<'board> for SimpleBoard<C> {
   type Neighbors = SimpleNeighbors<'board, C>;
 
   fn at(&self, x: u16, y: u16) -> Option<Self::Cell> {
-    let index = y as usize * self.size() + x as usize;
+    if x as usize >= self.width || y as usize >= self.height() {
+      return None;
+    }
 
     self
       .buf
-      .get(index)
+      .get(self.index(x, y))
       .copied()
-      .map(|ch| SimpleBoardCell::new(x, y, ch))
+      .map(|face| SimpleBoardCell::new(x, y, face))
   }
 
-  fn size(&self) -> usize {
-    (self.buf.len() as f32).sqrt() as usize
+  fn width(&self) -> usize {
+    self.width
+  }
+
+  fn height(&self) -> usize {
+    self.buf.len() / self.width
   }
 
   fn neighbors(&'board self, x: u16, y: u16) -> Self::Neighbors {
     SimpleNeighbors::new(self, x, y)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::EnglishAlphaSimpleBoard;
+  use crate::board::{Board, BoardCell};
+  use crate::generate::{dice::DiceSet, rng::SplitMix64};
+
+  #[test]
+  fn test_from_faces() {
+    let board = EnglishAlphaSimpleBoard::from_faces(&["Th", "e", "Qu", "In"]).unwrap();
+
+    let faces = |board: &EnglishAlphaSimpleBoard| {
+      [(0, 0), (1, 0), (0, 1), (1, 1)]
+        .map(|(x, y)| board.at(x, y).unwrap().face().to_vec())
+    };
+
+    assert_eq!(faces(&board), [vec![19, 7], vec![4], vec![16], vec![8, 13]]);
+    assert_eq!(board.at(0, 0).unwrap().ch(), 19);
+
+    let lowercase =
+      EnglishAlphaSimpleBoard::from_faces(&["th", "E", "qu", "in"]).unwrap();
+    assert_eq!(faces(&lowercase), faces(&board));
+
+    assert!(EnglishAlphaSimpleBoard::from_faces(&["a", "", "c", "d"]).is_err());
+    assert!(EnglishAlphaSimpleBoard::from_faces(&["a", "b-", "c", "d"]).is_err());
+    assert!(EnglishAlphaSimpleBoard::from_faces(&["a", "b", "c"]).is_err());
+  }
+
+  #[test]
+  fn test_code() {
+    let board = EnglishAlphaSimpleBoard::from_faces(&["Th", "e", "Qu", "x"]).unwrap();
+    assert_eq!(board.code(), "(th)eqx");
+
+    let decoded = EnglishAlphaSimpleBoard::from_code(&board.code()).unwrap();
+    assert_eq!(decoded.code(), board.code());
+
+    let board: EnglishAlphaSimpleBoard =
+      DiceSet::super_big().roll(&mut SplitMix64::new(3)).unwrap();
+    let decoded = EnglishAlphaSimpleBoard::from_code(&board.code()).unwrap();
+    assert_eq!(decoded.code(), board.code());
+
+    assert!(EnglishAlphaSimpleBoard::from_code("(th").is_err());
+    assert!(EnglishAlphaSimpleBoard::from_code("abc").is_err());
+
+    let board = EnglishAlphaSimpleBoard::from_code("3:ab(th)def").unwrap();
+    assert_eq!((board.width(), board.height()), (3, 2));
+    assert_eq!(board.at(2, 0).unwrap().face(), [19, 7]);
+    assert!(board.at(0, 2).is_none());
+    assert_eq!(board.code(), "3:ab(th)def");
+
+    assert!(EnglishAlphaSimpleBoard::from_code("4:abcdef").is_err());
+    assert!(EnglishAlphaSimpleBoard::from_code("x:abcd").is_err());
+  }
+}
@@ -12,6 +12,8 @@ pub struct EnglishAlpha;
 impl Charset for EnglishAlpha {
   type PrefixCharMap = EnglishAlphaMap;
 
+  const LEN: u8 = 26;
+
   #[inline]
   fn trim_special_characters(word: &str) -> String {
     word.to_lowercase().replace("qu", "q")
@@ -25,6 +27,22 @@ impl Charset for EnglishAlpha {
       _ => None,
     }
   }
+
+  #[inline]
+  fn from_prefix_char(ch: u8) -> Option<char> {
+    match ch {
+      0..=25 => Some((ch + b'a') as char),
+      _ => None,
+    }
+  }
+
+  #[inline]
+  fn to_letters(ch: u8) -> Option<String> {
+    match Self::from_prefix_char(ch)? {
+      'q' => Some("qu".to_string()),
+      ch => Some(ch.to_string()),
+    }
+  }
 }
 
 /// An english alphabet charset map.
@@ -46,6 +64,13 @@ impl CharsetMap for EnglishAlphaMap {
     self.0[ch as usize] = Some(val);
   }
 
+  /// # Panics
+  /// May panic if the byte is not a valid charset character.
+  #[inline]
+  fn remove(&mut self, ch: u8) -> Option<usize> {
+    self.0[ch as usize].take()
+  }
+
   #[inline]
   fn copy_from(&mut self, other: &Self) {
     for i in 0..26 {
@@ -10,11 +10,33 @@ pub trait Charset: Default {
   /// The character map type.
   type PrefixCharMap: CharsetMap;
 
+  /// The number of characters in the charset, prefix characters are `0..LEN`.
+  const LEN: u8;
+
   /// Gets a copy of supplied word with special characters removed.
   fn trim_special_characters(word: &str) -> String;
 
   /// Convert a character to a prefix charset compatible character if possible.
   fn to_prefix_char(ch: char) -> Option<u8>;
+
+  /// Convert a prefix charset character back to a character if possible.
+  fn from_prefix_char(ch: u8) -> Option<char>;
+
+  /// Convert a prefix charset character to the letters it stands for if possible, which
+  /// is a single letter unless special characters join several letters.
+  fn to_letters(ch: u8) -> Option<String> {
+    Self::from_prefix_char(ch).map(String::from)
+  }
+
+  /// Convert a word to prefix charset compatible characters if possible.
+  ///
+  /// Special characters are trimmed before conversion.
+  fn to_prefix_chars(word: &str) -> Option<Vec<u8>> {
+    Self::trim_special_characters(word)
+      .chars()
+      .map(Self::to_prefix_char)
+      .collect()
+  }
 }
 
 /// Describes a type that maps character bytes to `usize`.
@@ -25,6 +47,9 @@ pub trait CharsetMap: Default + Debug + Codegen {
   /// Inserts a value into map using supplied character as a key.
   fn insert(&mut self, ch: u8, val: usize);
 
+  /// Removes a value from map using supplied character as a key.
+  fn remove(&mut self, ch: u8) -> Option<usize>;
+
   /// Copies map contents from another map.
   fn copy_from(&mut self, other: &Self);
 }
@@ -0,0 +1,271 @@
+//! Multiplayer boggle rounds.
+
+use crate::{
+  board::Board, charset::Charset, scoring::Scoring, search::valid::is_word_on_board,
+  trie::Prefixes,
+};
+use std::{
+  collections::{HashMap, HashSet},
+  fmt,
+};
+
+/// The words a player wrote down during a round.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Submission {
+  /// The name of the player.
+  pub player: String,
+  /// The words, as typed by the player.
+  pub words: Vec<String>,
+}
+
+impl Submission {
+  /// Create a [Submission].
+  pub fn new<W: AsRef<str>>(player: &str, words: &[W]) -> Self {
+    Self {
+      player: player.to_string(),
+      words: words.iter().map(|word| word.as_ref().to_string()).collect(),
+    }
+  }
+}
+
+/// The reason a submitted word does not count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+  /// The word is shorter than the minimum word length.
+  TooShort,
+  /// The word is not in the dictionary.
+  NotAWord,
+  /// The word cannot be traced on the board.
+  NotOnBoard,
+  /// The player already submitted the word.
+  Repeated,
+}
+
+impl fmt::Display for Rejection {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::TooShort => "too short",
+      Self::NotAWord => "not a word",
+      Self::NotOnBoard => "not on the board",
+      Self::Repeated => "already submitted",
+    })
+  }
+}
+
+/// The result of a submitted word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+  /// The word is valid and only this player found it.
+  Scored {
+    /// The id of the word in the dictionary.
+    id: usize,
+    /// The points of the word.
+    points: u32,
+  },
+  /// The word is valid but another player also found it, so it scores nothing.
+  Cancelled {
+    /// The id of the word in the dictionary.
+    id: usize,
+  },
+  /// The word does not count.
+  Rejected(Rejection),
+}
+
+/// A submitted word and its result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+  /// The word, as typed by the player.
+  pub word: String,
+  /// The result of the word.
+  pub outcome: Outcome,
+}
+
+/// A player's scored words for a round.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Scoresheet {
+  /// The name of the player.
+  pub player: String,
+  /// The submitted words, in submission order.
+  pub entries: Vec<Entry>,
+  /// The points of every scored word.
+  pub total: u32,
+}
+
+/// A round of boggle played by several players on a board.
+pub struct Round<'a, B, C: Charset, M, S> {
+  board: &'a B,
+  prefixes: &'a Prefixes<C, M>,
+  scoring: S,
+  min_len: usize,
+}
+
+impl<'a, B: Board<'a>, C: Charset, M, S: Scoring> Round<'a, B, C, M, S> {
+  /// Create a [Round] with a minimum word length of 3.
+  pub fn new(board: &'a B, prefixes: &'a Prefixes<C, M>, scoring: S) -> Self {
+    Self {
+      board,
+      prefixes,
+      scoring,
+      min_len: 3,
+    }
+  }
+
+  /// Get a [Round] with a minimum word length, counted in letters.
+  pub fn with_min_len(mut self, min_len: usize) -> Self {
+    self.min_len = min_len;
+    self
+  }
+
+  /// Validates a single word, returning its dictionary id.
+  pub fn validate(&self, word: &str) -> Result<usize, Rejection> {
+    let word = word.trim();
+    if word.chars().count() < self.min_len {
+      return Err(Rejection::TooShort);
+    }
+
+    let id = self.prefixes.get(word).ok_or(Rejection::NotAWord)?;
+    if !is_word_on_board::<C, _>(self.board, word) {
+      return Err(Rejection::NotOnBoard);
+    }
+
+    Ok(id)
+  }
+
+  /// Scores every player's submission, cancelling words found by more than one player.
+  ///
+  /// Scoresheets are in the order of `submissions`.
+  pub fn score(&self, submissions: &[Submission]) -> Vec<Scoresheet> {
+    let validated = submissions
+      .iter()
+      .map(|submission| {
+        let mut seen = HashSet::new();
+
+        submission
+          .words
+          .iter()
+          .map(|word| {
+            let id = self.validate(word)?;
+            match seen.insert(id) {
+              true => Ok(id),
+              false => Err(Rejection::Repeated),
+            }
+          })
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+
+    let mut finders = HashMap::<usize, usize>::new();
+    for id in validated.iter().flatten().flatten() {
+      *finders.entry(*id).or_default() += 1;
+    }
+
+    submissions
+      .iter()
+      .zip(validated)
+      .map(|(submission, results)| {
+        let entries = submission
+          .words
+          .iter()
+          .zip(results)
+          .map(|(word, result)| Entry {
+            word: word.clone(),
+            outcome: match result {
+              Ok(id) if finders[&id] > 1 => Outcome::Cancelled { id },
+              Ok(id) => Outcome::Scored {
+                id,
+                points: self
+                  .prefixes
+                  .word(id)
+                  .map_or(0, |word| self.scoring.score(word)),
+              },
+              Err(rejection) => Outcome::Rejected(rejection),
+            },
+          })
+          .collect::<Vec<_>>();
+
+        let total = entries
+          .iter()
+          .map(|entry| match entry.outcome {
+            Outcome::Scored { points, .. } => points,
+            _ => 0,
+          })
+          .sum();
+
+        Scoresheet {
+          player: submission.player.clone(),
+          entries,
+          total,
+        }
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Outcome, Rejection, Round, Submission};
+  use crate::{
+    board::simple::EnglishAlphaSimpleBoard, scoring::LengthTable,
+    trie::EnglishAlphaPrefixes,
+  };
+
+  #[test]
+  fn test_validate() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&["at", "tea", "eat", "teas"], 50);
+    let board = EnglishAlphaSimpleBoard::new("teax").unwrap();
+    let round = Round::new(&board, &prefixes, LengthTable::official());
+
+    assert_eq!(round.validate(" Tea "), Ok(2));
+    assert_eq!(round.validate("at"), Err(Rejection::TooShort));
+    assert_eq!(round.validate("tex"), Err(Rejection::NotAWord));
+    assert_eq!(round.validate("teas"), Err(Rejection::NotOnBoard));
+    assert_eq!(round.with_min_len(2).validate("at"), Ok(0));
+  }
+
+  #[test]
+  fn test_score() {
+    let words = ["tea", "eat", "ate", "axe", "texas"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    // t e z
+    // a x z
+    // s z z
+    let board = EnglishAlphaSimpleBoard::new("tezaxzszz").unwrap();
+    let round = Round::new(&board, &prefixes, LengthTable::official());
+
+    let sheets = round.score(&[
+      Submission::new("ann", &["tea", "texas", "tea", "zzz"]),
+      Submission::new("bo", &["tea", "axe", "at"]),
+    ]);
+
+    let outcomes = sheets
+      .iter()
+      .map(|sheet| {
+        sheet
+          .entries
+          .iter()
+          .map(|entry| entry.outcome)
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      outcomes,
+      [
+        vec![
+          Outcome::Cancelled { id: 3 },
+          Outcome::Scored { id: 4, points: 2 },
+          Outcome::Rejected(Rejection::Repeated),
+          Outcome::Rejected(Rejection::NotAWord),
+        ],
+        vec![
+          Outcome::Cancelled { id: 3 },
+          Outcome::Scored { id: 1, points: 1 },
+          Outcome::Rejected(Rejection::TooShort),
+        ],
+      ]
+    );
+    assert_eq!(sheets[0].player, "ann");
+    assert_eq!((sheets[0].total, sheets[1].total), (2, 1));
+  }
+}
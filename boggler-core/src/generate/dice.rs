@@ -0,0 +1,214 @@
+//! Boggle dice sets.
+
+use super::rng::Rng;
+use crate::{
+  board::{is_1d_len_square_in_2d, simple::SimpleBoard},
+  charset::Charset,
+};
+use eyre::Result;
+
+/// The classic boggle dice, sold until 1987.
+const CLASSIC: [&str; 16] = [
+  "AACIOT", "ABILTY", "ABJMOQ", "ACDEMP", "ACELRS", "ADENVZ", "AHMORS", "BIFORX",
+  "DENOSW", "DKNOTU", "EEFHIY", "EGKLUY", "EGINTV", "EHINPS", "ELPSTU", "GILRUW",
+];
+
+/// The boggle dice sold since 1987.
+const NEW: [&str; 16] = [
+  "AAEEGN", "ABBJOO", "ACHOPS", "AFFKPS", "AOOTTW", "CIMOTU", "DEILRX", "DELRVY",
+  "DISTTY", "EEGHNW", "EEINSU", "EHRTVW", "EIOSST", "ELRTTY", "HIMNUQ", "HLNNRZ",
+];
+
+/// The big boggle dice, for a 5x5 board.
+const BIG: [&str; 25] = [
+  "AAAFRS", "AAEEEE", "AAFIRS", "ADENNN", "AEEEEM", "AEEGMU", "AEGMNN", "AFIRSY",
+  "BJKQXZ", "CCENST", "CEIILT", "CEILPT", "CEIPST", "DDHNOT", "DHHLOR", "DHLNOR",
+  "DHLNOR", "EIIITT", "EMOTTT", "ENSSSU", "FIPRSY", "GORRVW", "IPRRRY", "NOOTUW",
+  "OOOTTU",
+];
+
+/// The super big boggle dice, for a 6x6 board, with faces separated by spaces.
+///
+/// The three blank faces of the `EIO` die are left out, as every cell needs a letter.
+const SUPER_BIG: [&str; 36] = [
+  "A A A F R S",
+  "A A E E E E",
+  "A A E E O O",
+  "A A F I R S",
+  "A B D E I O",
+  "A D E N N N",
+  "A E E E E M",
+  "A E E G M U",
+  "A E G M N N",
+  "A E I L M N",
+  "A E I N O U",
+  "A F I R S Y",
+  "An Er He In Qu Th",
+  "B B J K X Z",
+  "C C E N S T",
+  "C D D L N N",
+  "C E I I T T",
+  "C E I P S T",
+  "C F G N U Y",
+  "D D H N O T",
+  "D H H L O R",
+  "D H H N O W",
+  "D H L N O R",
+  "E H I L R S",
+  "E I I L S T",
+  "E I L P S T",
+  "E I O",
+  "E M T T T O",
+  "E N S S S U",
+  "G O R R V W",
+  "H I R S T V",
+  "H O P R S T",
+  "I P R S Y Y",
+  "J K Qu W X Z",
+  "N O O T U W",
+  "O O O T T U",
+];
+
+/// A set of dice, one for each cell of a square board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceSet {
+  dice: Vec<Vec<String>>,
+}
+
+impl DiceSet {
+  /// Create a [DiceSet] from the faces of each die.
+  pub fn new(dice: Vec<Vec<String>>) -> Result<Self> {
+    if dice.is_empty() || !is_1d_len_square_in_2d(dice.len()) {
+      eyre::bail!("expected a square number of dice, found {}", dice.len());
+    }
+
+    if dice.iter().any(|die| die.is_empty()) {
+      eyre::bail!("expected every die to have a face");
+    }
+
+    Ok(Self { dice })
+  }
+
+  /// The classic boggle dice, sold until 1987.
+  pub fn classic() -> Self {
+    Self::from_letters(&CLASSIC)
+  }
+
+  /// The boggle dice sold since 1987.
+  pub fn new_boggle() -> Self {
+    Self::from_letters(&NEW)
+  }
+
+  /// The big boggle dice, for a 5x5 board.
+  pub fn big() -> Self {
+    Self::from_letters(&BIG)
+  }
+
+  /// The super big boggle dice, for a 6x6 board, including a die of multi-letter faces.
+  pub fn super_big() -> Self {
+    let dice = SUPER_BIG
+      .iter()
+      .map(|die| die.split(' ').map(String::from).collect())
+      .collect();
+
+    Self { dice }
+  }
+
+  /// Create a built-in [DiceSet] of single letter dice, where `Q` is a `Qu` face.
+  fn from_letters(dice: &[&str]) -> Self {
+    let dice = dice
+      .iter()
+      .map(|die| {
+        die
+          .chars()
+          .map(|face| match face {
+            'Q' => "Qu".to_string(),
+            face => face.to_string(),
+          })
+          .collect()
+      })
+      .collect();
+
+    Self { dice }
+  }
+
+  /// Gets the faces of each die.
+  pub fn dice(&self) -> &[Vec<String>] {
+    &self.dice
+  }
+
+  /// Gets the width and height of the board the dice fill.
+  pub fn size(&self) -> usize {
+    (self.dice.len() as f64).sqrt() as usize
+  }
+
+  /// Shuffles the dice into the board cells and rolls each die, returning the face of
+  /// each cell, row by row.
+  pub fn roll_faces<R: Rng>(&self, rng: &mut R) -> Vec<&str> {
+    let mut order = (0..self.dice.len()).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+
+    order
+      .into_iter()
+      .map(|die| {
+        let faces = &self.dice[die];
+        faces[rng.below(faces.len())].as_str()
+      })
+      .collect()
+  }
+
+  /// Shuffles and rolls the dice into a board.
+  ///
+  /// Fails if a face contains characters not in the charset.
+  pub fn roll<C: Charset, R: Rng>(&self, rng: &mut R) -> Result<SimpleBoard<C>> {
+    SimpleBoard::from_faces(&self.roll_faces(rng))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::DiceSet;
+  use crate::{
+    board::{simple::EnglishAlphaSimpleBoard, Board},
+    generate::rng::SplitMix64,
+  };
+
+  #[test]
+  fn test_dice_sets() {
+    let sets = [
+      DiceSet::classic(),
+      DiceSet::new_boggle(),
+      DiceSet::big(),
+      DiceSet::super_big(),
+    ];
+
+    let sizes = sets.iter().map(DiceSet::size).collect::<Vec<_>>();
+    assert_eq!(sizes, [4, 4, 5, 6]);
+
+    for set in &sets {
+      assert!(DiceSet::new(set.dice().to_vec()).is_ok());
+      assert!(set
+        .dice()
+        .iter()
+        .all(|die| die.len() == 6 || die.len() == 3));
+    }
+
+    assert!(DiceSet::new(vec![vec!["a".to_string()]; 3]).is_err());
+    assert!(DiceSet::new(vec![vec![]]).is_err());
+  }
+
+  #[test]
+  fn test_roll() {
+    let set = DiceSet::super_big();
+
+    let faces = set.roll_faces(&mut SplitMix64::new(42));
+    assert_eq!(faces.len(), 36);
+    assert_eq!(faces, set.roll_faces(&mut SplitMix64::new(42)));
+    assert!(faces
+      .iter()
+      .all(|face| set.dice().iter().any(|die| die.iter().any(|f| f == face))));
+
+    let board: EnglishAlphaSimpleBoard = set.roll(&mut SplitMix64::new(42)).unwrap();
+    assert_eq!((board.width(), board.height()), (6, 6));
+  }
+}
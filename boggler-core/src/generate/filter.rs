@@ -0,0 +1,240 @@
+//! Board quality filters.
+
+use crate::{
+  board::{simple::SimpleBoard, Board, BoardCell},
+  charset::Charset,
+  scoring::{BoardScore, Scoring},
+  search::{find_words, options::SearchOptions},
+  trie::Prefixes,
+};
+use eyre::Result;
+use std::collections::HashMap;
+
+/// The statistics of a board.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BoardStats {
+  /// The number of distinct words found.
+  pub words: usize,
+  /// The points of the words found.
+  pub score: BoardScore,
+  /// The length of the longest word found, counted in letters.
+  pub longest: usize,
+  /// The most cells sharing a single face.
+  pub repeats: usize,
+}
+
+/// A board that passed a [BoardFilter].
+#[derive(Debug, Clone)]
+pub struct Accepted<C: Charset> {
+  /// The accepted board.
+  pub board: SimpleBoard<C>,
+  /// The statistics of the board.
+  pub stats: BoardStats,
+  /// The number of boards generated, including the accepted board.
+  pub attempts: usize,
+}
+
+/// Rejects generated boards that are too easy, too hard or too repetitive.
+pub struct BoardFilter<'a, C: Charset, M, S> {
+  prefixes: &'a Prefixes<C, M>,
+  scoring: S,
+  options: SearchOptions,
+  words: (Option<usize>, Option<usize>),
+  score: (Option<u32>, Option<u32>),
+  word_len: Option<usize>,
+  max_repeats: Option<usize>,
+  attempts: usize,
+}
+
+impl<'a, C: Charset, M, S: Scoring> BoardFilter<'a, C, M, S> {
+  /// Create a [BoardFilter] accepting any board, within 100 attempts.
+  pub fn new(prefixes: &'a Prefixes<C, M>, scoring: S) -> Self {
+    Self {
+      prefixes,
+      scoring,
+      options: SearchOptions::new(),
+      words: (None, None),
+      score: (None, None),
+      word_len: None,
+      max_repeats: None,
+      attempts: 100,
+    }
+  }
+
+  /// Get a [BoardFilter] finding words with the given options.
+  pub fn with_options(mut self, options: SearchOptions) -> Self {
+    self.options = options;
+    self
+  }
+
+  /// Get a [BoardFilter] rejecting boards with fewer distinct words.
+  pub fn with_min_words(mut self, min_words: usize) -> Self {
+    self.words.0 = Some(min_words);
+    self
+  }
+
+  /// Get a [BoardFilter] rejecting boards with more distinct words.
+  pub fn with_max_words(mut self, max_words: usize) -> Self {
+    self.words.1 = Some(max_words);
+    self
+  }
+
+  /// Get a [BoardFilter] rejecting boards scoring fewer points.
+  pub fn with_min_score(mut self, min_score: u32) -> Self {
+    self.score.0 = Some(min_score);
+    self
+  }
+
+  /// Get a [BoardFilter] rejecting boards scoring more points.
+  pub fn with_max_score(mut self, max_score: u32) -> Self {
+    self.score.1 = Some(max_score);
+    self
+  }
+
+  /// Get a [BoardFilter] rejecting boards without a word of at least `word_len` letters.
+  pub fn with_word_of_len(mut self, word_len: usize) -> Self {
+    self.word_len = Some(word_len);
+    self
+  }
+
+  /// Get a [BoardFilter] rejecting boards with more than `max_repeats` cells sharing a
+  /// face.
+  pub fn with_max_repeats(mut self, max_repeats: usize) -> Self {
+    self.max_repeats = Some(max_repeats);
+    self
+  }
+
+  /// Get a [BoardFilter] giving up after generating `attempts` boards.
+  pub fn with_attempts(mut self, attempts: usize) -> Self {
+    self.attempts = attempts;
+    self
+  }
+
+  /// Gets the statistics of a board.
+  pub fn stats(&self, board: &SimpleBoard<C>) -> BoardStats {
+    let mut words = Vec::new();
+    find_words(board, self.prefixes, &self.options, &mut words);
+
+    let score = self.scoring.score_words(self.prefixes, &words);
+    let longest = score
+      .words
+      .iter()
+      .filter_map(|(id, _)| self.prefixes.word(*id))
+      .map(|word| word.chars().count())
+      .max()
+      .unwrap_or(0);
+
+    let mut faces = HashMap::<&[u8], usize>::new();
+    let cells = (0..board.height() as u16)
+      .flat_map(|y| (0..board.width() as u16).filter_map(move |x| board.at(x, y)))
+      .collect::<Vec<_>>();
+    for cell in &cells {
+      *faces.entry(cell.face()).or_default() += 1;
+    }
+
+    BoardStats {
+      words: score.words.len(),
+      longest,
+      repeats: faces.values().copied().max().unwrap_or(0),
+      score,
+    }
+  }
+
+  /// Determines if board statistics pass every filter.
+  pub fn accepts(&self, stats: &BoardStats) -> bool {
+    within(stats.words, self.words)
+      && within(stats.score.total, self.score)
+      && self.word_len.is_none_or(|len| stats.longest >= len)
+      && self.max_repeats.is_none_or(|max| stats.repeats <= max)
+  }
+
+  /// Generates boards until one passes every filter.
+  ///
+  /// Fails if `generate` fails or no board passes within the attempts.
+  pub fn generate<F>(&self, mut generate: F) -> Result<Accepted<C>>
+  where
+    F: FnMut() -> Result<SimpleBoard<C>>,
+  {
+    for attempts in 1..=self.attempts {
+      let board = generate()?;
+      let stats = self.stats(&board);
+
+      if self.accepts(&stats) {
+        return Ok(Accepted {
+          board,
+          stats,
+          attempts,
+        });
+      }
+    }
+
+    eyre::bail!("no board passed the filters in {} attempts", self.attempts)
+  }
+}
+
+/// Determines if a value is within optional inclusive bounds.
+fn within<T: PartialOrd>(value: T, (min, max): (Option<T>, Option<T>)) -> bool {
+  min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::BoardFilter;
+  use crate::{
+    board::simple::EnglishAlphaSimpleBoard,
+    generate::{dice::DiceSet, rng::SplitMix64},
+    scoring::LengthTable,
+    trie::EnglishAlphaPrefixes,
+  };
+
+  #[test]
+  fn test_stats() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&["tea", "eat", "teat", "seat"], 50);
+    let filter = BoardFilter::new(&prefixes, LengthTable::official());
+
+    // t e
+    // a t
+    let board = EnglishAlphaSimpleBoard::new("teat").unwrap();
+    let stats = filter.stats(&board);
+
+    assert_eq!(stats.words, 3);
+    assert_eq!(stats.score.total, 3);
+    assert_eq!(stats.longest, 4);
+    assert_eq!(stats.repeats, 2);
+    assert!(filter.accepts(&stats));
+
+    assert!(!filter.with_word_of_len(5).accepts(&stats));
+
+    let filter = BoardFilter::new(&prefixes, LengthTable::official());
+    assert!(!filter.with_max_repeats(1).accepts(&stats));
+
+    let filter = BoardFilter::new(&prefixes, LengthTable::official());
+    assert!(!filter.with_min_words(4).accepts(&stats));
+
+    let filter = BoardFilter::new(&prefixes, LengthTable::official());
+    assert!(!filter.with_max_score(2).accepts(&stats));
+  }
+
+  #[test]
+  fn test_generate() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&["tea", "eat", "ate", "eta"], 50);
+    let dice = DiceSet::new_boggle();
+    let mut rng = SplitMix64::new(4);
+
+    let accepted = BoardFilter::new(&prefixes, LengthTable::official())
+      .with_min_words(1)
+      .with_attempts(1000)
+      .generate(|| dice.roll(&mut rng))
+      .unwrap();
+
+    assert!(accepted.stats.words >= 1);
+    assert!(accepted.attempts >= 1);
+
+    let rejected = BoardFilter::new(&prefixes, LengthTable::official())
+      .with_min_words(5)
+      .with_attempts(3)
+      .generate(|| dice.roll(&mut rng));
+
+    assert!(rejected.is_err());
+  }
+}
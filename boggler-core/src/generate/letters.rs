@@ -0,0 +1,261 @@
+//! Letter frequency board generation.
+
+use super::rng::Rng;
+use crate::{board::simple::SimpleBoard, charset::Charset, trie::Prefixes};
+use eyre::Result;
+use std::marker::PhantomData;
+
+/// The characters counted as vowels unless [LetterWeights::with_vowels] is used.
+const VOWELS: &str = "aeiou";
+
+/// Weights of each charset character, used to roll boards of any size one cell at a
+/// time.
+#[derive(Debug, Clone)]
+pub struct LetterWeights<C: Charset> {
+  weights: Vec<usize>,
+  vowels: Vec<bool>,
+  vowel_range: Option<(f64, f64)>,
+  _phantom: PhantomData<C>,
+}
+
+impl<C: Charset> LetterWeights<C> {
+  /// Create [LetterWeights] from the weight of each prefix character, indexed by prefix
+  /// character.
+  pub fn new(mut weights: Vec<usize>) -> Self {
+    weights.resize(C::LEN as usize, 0);
+
+    Self {
+      weights,
+      vowels: Self::vowel_mask(VOWELS),
+      vowel_range: None,
+      _phantom: Default::default(),
+    }
+  }
+
+  /// Create [LetterWeights] from how often each character appears in a list of words.
+  ///
+  /// Words with characters not in the charset are skipped.
+  pub fn from_words<W: AsRef<str>>(words: &[W]) -> Self {
+    let mut weights = vec![0; C::LEN as usize];
+
+    for chars in words
+      .iter()
+      .filter_map(|word| C::to_prefix_chars(word.as_ref()))
+    {
+      for ch in chars {
+        weights[ch as usize] += 1;
+      }
+    }
+
+    Self::new(weights)
+  }
+
+  /// Create [LetterWeights] from the letter frequencies of the words in a dictionary,
+  /// skipping removed words.
+  pub fn from_prefixes<M>(prefixes: &Prefixes<C, M>) -> Self {
+    let words = prefixes
+      .iter()
+      .filter_map(|id| prefixes.word(id))
+      .collect::<Vec<_>>();
+
+    Self::from_words(&words)
+  }
+
+  /// Sets the characters counted as vowels, ignoring characters not in the charset.
+  pub fn with_vowels(mut self, vowels: &str) -> Self {
+    self.vowels = Self::vowel_mask(vowels);
+    self
+  }
+
+  /// Keeps the share of vowel cells of generated boards between `min` and `max`.
+  pub fn with_vowel_ratio(mut self, min: f64, max: f64) -> Self {
+    self.vowel_range = Some((min, max));
+    self
+  }
+
+  /// Gets the weight of each prefix character.
+  pub fn weights(&self) -> &[usize] {
+    &self.weights
+  }
+
+  /// Determines if a prefix character is a vowel.
+  pub fn is_vowel(&self, ch: u8) -> bool {
+    self.vowels.get(ch as usize).copied().unwrap_or(false)
+  }
+
+  /// Picks a random prefix character by weight, or `None` if every weight is zero.
+  pub fn sample<R: Rng>(&self, rng: &mut R) -> Option<u8> {
+    self.sample_where(rng, |_| true)
+  }
+
+  /// Generates the prefix character of each cell of a board of `len` cells.
+  ///
+  /// Fails if every weight is zero or the vowel ratio cannot be met.
+  pub fn generate_chars<R: Rng>(&self, len: usize, rng: &mut R) -> Result<Vec<u8>> {
+    let mut chars = (0..len)
+      .map(|_| self.sample(rng))
+      .collect::<Option<Vec<_>>>()
+      .ok_or(eyre::eyre!("expected a character with a weight above zero"))?;
+
+    let (min, max) = match self.vowel_range {
+      Some((min, max)) => (
+        (min * len as f64).ceil() as usize,
+        (max * len as f64).floor() as usize,
+      ),
+      None => return Ok(chars),
+    };
+
+    if min > max {
+      eyre::bail!("no vowel count of a {len} cell board is in the vowel ratio");
+    }
+
+    loop {
+      let vowels = chars.iter().filter(|&&ch| self.is_vowel(ch)).count();
+
+      // Replace a random cell of the surplus kind until the count is in range.
+      let vowel = match vowels {
+        vowels if vowels < min => true,
+        vowels if vowels > max => false,
+        _ => return Ok(chars),
+      };
+
+      let ch = self
+        .sample_where(rng, |ch| self.is_vowel(ch) == vowel)
+        .ok_or(eyre::eyre!(
+          "expected a {} with a weight above zero",
+          if vowel { "vowel" } else { "consonant" }
+        ))?;
+
+      let cells = (0..len)
+        .filter(|&index| self.is_vowel(chars[index]) != vowel)
+        .collect::<Vec<_>>();
+
+      chars[cells[rng.below(cells.len())]] = ch;
+    }
+  }
+
+  /// Generates a board of `width` by `height` cells.
+  ///
+  /// Fails if every weight is zero or the vowel ratio cannot be met.
+  pub fn generate<R: Rng>(
+    &self,
+    width: usize,
+    height: usize,
+    rng: &mut R,
+  ) -> Result<SimpleBoard<C>> {
+    let faces = self
+      .generate_chars(width * height, rng)?
+      .into_iter()
+      .filter_map(C::from_prefix_char)
+      .map(String::from)
+      .collect::<Vec<_>>();
+
+    SimpleBoard::from_faces_with_width(&faces, width)
+  }
+
+  fn sample_where<R: Rng, F: Fn(u8) -> bool>(
+    &self,
+    rng: &mut R,
+    filter: F,
+  ) -> Option<u8> {
+    let weight = |ch: usize| match filter(ch as u8) {
+      true => self.weights[ch],
+      false => 0,
+    };
+
+    let total = (0..self.weights.len()).map(weight).sum::<usize>();
+    if total == 0 {
+      return None;
+    }
+
+    let mut target = rng.below(total);
+    for ch in 0..self.weights.len() {
+      match target.checked_sub(weight(ch)) {
+        Some(rest) => target = rest,
+        None => return Some(ch as u8),
+      }
+    }
+
+    None
+  }
+
+  fn vowel_mask(vowels: &str) -> Vec<bool> {
+    let mut mask = vec![false; C::LEN as usize];
+
+    for ch in vowels.chars().filter_map(C::to_prefix_char) {
+      mask[ch as usize] = true;
+    }
+
+    mask
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::LetterWeights;
+  use crate::{
+    board::{Board, BoardCell},
+    charset::english_alpha::EnglishAlpha,
+    generate::rng::SplitMix64,
+    trie::EnglishAlphaPrefixes,
+  };
+
+  #[test]
+  fn test_from_words() {
+    let weights = LetterWeights::<EnglishAlpha>::from_words(&["tea", "Quit", "t3a"]);
+
+    assert_eq!(weights.weights().len(), 26);
+    assert_eq!(weights.weights()[19], 2);
+    assert_eq!(weights.weights()[16], 1);
+    assert_eq!(weights.weights()[20], 0);
+    assert!(weights.is_vowel(0));
+    assert!(!weights.is_vowel(19));
+
+    let mut prefixes = EnglishAlphaPrefixes::from_words(&["tea", "zoo"], 50);
+    prefixes.remove("zoo");
+
+    let weights = LetterWeights::from_prefixes(&prefixes);
+    assert_eq!(weights.weights()[19], 1);
+    assert_eq!(weights.weights()[25], 0);
+  }
+
+  #[test]
+  fn test_generate() {
+    let weights = LetterWeights::<EnglishAlpha>::from_words(&["tea", "seat", "stream"]);
+
+    let board = weights.generate(5, 3, &mut SplitMix64::new(1)).unwrap();
+    assert_eq!((board.width(), board.height()), (5, 3));
+
+    let again = weights.generate(5, 3, &mut SplitMix64::new(1)).unwrap();
+    assert_eq!(again.code(), board.code());
+    assert!(board.code().starts_with("5:"));
+    assert!(board.code()[2..].chars().all(|ch| "teasrm".contains(ch)));
+
+    assert!(LetterWeights::<EnglishAlpha>::new(vec![])
+      .generate(2, 2, &mut SplitMix64::new(1))
+      .is_err());
+  }
+
+  #[test]
+  fn test_vowel_ratio() {
+    let weights = LetterWeights::<EnglishAlpha>::from_words(&["strength", "a"])
+      .with_vowel_ratio(0.4, 0.5);
+    let mut rng = SplitMix64::new(2);
+
+    for _ in 0..10 {
+      let board = weights.generate(4, 4, &mut rng).unwrap();
+      let vowels = (0..16)
+        .filter(|index| weights.is_vowel(board.at(index % 4, index / 4).unwrap().ch()))
+        .count();
+
+      assert!((7..=8).contains(&vowels));
+    }
+
+    let weights = weights.with_vowels("y");
+    assert!(weights.generate(4, 4, &mut rng).is_err());
+
+    let weights =
+      LetterWeights::<EnglishAlpha>::from_words(&["ab"]).with_vowel_ratio(0.6, 0.65);
+    assert!(weights.generate(2, 2, &mut rng).is_err());
+  }
+}
@@ -0,0 +1,8 @@
+//! Random boggle board generation.
+
+pub mod dice;
+pub mod filter;
+pub mod letters;
+pub mod optimize;
+pub mod rng;
+pub mod seed;
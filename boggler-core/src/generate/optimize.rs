@@ -0,0 +1,321 @@
+//! High scoring board search.
+//!
+//! Boards are improved by simulated annealing: each step swaps two cells or changes the
+//! face of one cell, and keeps the change if the board scores higher, or with a chance
+//! that shrinks as the temperature cools if it scores lower. A temperature of zero is
+//! plain hill climbing.
+
+use super::{dice::DiceSet, letters::LetterWeights, rng::Rng};
+use crate::{
+  board::simple::SimpleBoard,
+  charset::Charset,
+  scoring::{BoardScore, Scoring},
+  search::{find_words, options::SearchOptions},
+  trie::Prefixes,
+};
+use eyre::Result;
+use std::time::{Duration, Instant};
+
+/// The best board found by an [Optimizer].
+#[derive(Debug, Clone)]
+pub struct Optimized<C: Charset> {
+  /// The highest scoring board.
+  pub board: SimpleBoard<C>,
+  /// The points of the words found on the board.
+  pub score: BoardScore,
+  /// The number of changes tried.
+  pub iterations: usize,
+}
+
+/// Searches for the highest scoring board, using the solver and a [Scoring] as the
+/// objective.
+pub struct Optimizer<'a, C: Charset, M, S> {
+  prefixes: &'a Prefixes<C, M>,
+  scoring: S,
+  options: SearchOptions,
+  iterations: usize,
+  timeout: Option<Duration>,
+  temperature: f64,
+}
+
+/// The faces of a board being optimized, and the die of each cell in dice mode.
+#[derive(Clone)]
+struct State {
+  faces: Vec<String>,
+  dice: Option<Vec<usize>>,
+}
+
+impl<'a, C: Charset, M, S: Scoring> Optimizer<'a, C, M, S> {
+  /// Create an [Optimizer] trying 1000 changes with a starting temperature of 1.
+  pub fn new(prefixes: &'a Prefixes<C, M>, scoring: S) -> Self {
+    Self {
+      prefixes,
+      scoring,
+      options: SearchOptions::new(),
+      iterations: 1000,
+      timeout: None,
+      temperature: 1.0,
+    }
+  }
+
+  /// Get an [Optimizer] finding words with the given options.
+  pub fn with_options(mut self, options: SearchOptions) -> Self {
+    self.options = options;
+    self
+  }
+
+  /// Get an [Optimizer] trying at most `iterations` changes.
+  pub fn with_iterations(mut self, iterations: usize) -> Self {
+    self.iterations = iterations;
+    self
+  }
+
+  /// Get an [Optimizer] that stops after `timeout`, or after its iterations if sooner.
+  pub fn with_timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = Some(timeout);
+    self
+  }
+
+  /// Get an [Optimizer] with a starting temperature, in points, that cools to zero over
+  /// the budget.
+  pub fn with_temperature(mut self, temperature: f64) -> Self {
+    self.temperature = temperature;
+    self
+  }
+
+  /// Optimizes a board of `width` by `height` cells, changing cells to letters picked
+  /// by weight.
+  pub fn optimize<R: Rng>(
+    &self,
+    letters: &LetterWeights<C>,
+    width: usize,
+    height: usize,
+    rng: &mut R,
+  ) -> Result<Optimized<C>> {
+    let faces = letters
+      .generate_chars(width * height, rng)?
+      .into_iter()
+      .filter_map(C::from_prefix_char)
+      .map(String::from)
+      .collect();
+
+    let state = State { faces, dice: None };
+
+    self.anneal(state, width, rng, |_, _, rng| {
+      letters
+        .sample(rng)
+        .and_then(C::from_prefix_char)
+        .map(String::from)
+        .unwrap_or_default()
+    })
+  }
+
+  /// Optimizes a board that can be rolled with a dice set, changing cells to other
+  /// faces of their die.
+  pub fn optimize_dice<R: Rng>(
+    &self,
+    dice: &DiceSet,
+    rng: &mut R,
+  ) -> Result<Optimized<C>> {
+    let mut order = (0..dice.dice().len()).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+
+    let faces = order
+      .iter()
+      .map(|&die| {
+        let faces = &dice.dice()[die];
+        faces[rng.below(faces.len())].clone()
+      })
+      .collect();
+
+    let state = State {
+      faces,
+      dice: Some(order),
+    };
+
+    self.anneal(state, dice.size(), rng, |state, cell, rng| {
+      let die = state.dice.as_ref().map_or(0, |order| order[cell]);
+      let faces = &dice.dice()[die];
+      faces[rng.below(faces.len())].clone()
+    })
+  }
+
+  /// Runs simulated annealing from a starting state, where `face` picks a new face for a
+  /// cell.
+  fn anneal<R: Rng, F: Fn(&State, usize, &mut R) -> String>(
+    &self,
+    state: State,
+    width: usize,
+    rng: &mut R,
+    face: F,
+  ) -> Result<Optimized<C>> {
+    let start = Instant::now();
+    let cells = state.faces.len();
+
+    let (board, score) = self.evaluate(&state, width)?;
+    let mut current = (state, score.total);
+    let mut best = Optimized {
+      board,
+      score,
+      iterations: 0,
+    };
+
+    while best.iterations < self.iterations {
+      let elapsed = start.elapsed();
+      if self.timeout.is_some_and(|timeout| elapsed >= timeout) {
+        break;
+      }
+
+      let progress = match self.timeout {
+        Some(timeout) => elapsed.as_secs_f64() / timeout.as_secs_f64(),
+        None => 0.0,
+      }
+      .max(best.iterations as f64 / self.iterations as f64);
+      let temperature = self.temperature * (1.0 - progress);
+
+      best.iterations += 1;
+
+      let mut candidate = current.0.clone();
+      match rng.below(2) {
+        0 => {
+          let (a, b) = (rng.below(cells), rng.below(cells));
+          candidate.faces.swap(a, b);
+          if let Some(dice) = &mut candidate.dice {
+            dice.swap(a, b);
+          }
+        }
+        _ => {
+          let cell = rng.below(cells);
+          candidate.faces[cell] = face(&candidate, cell, rng);
+        }
+      }
+
+      let (board, score) = self.evaluate(&candidate, width)?;
+      let delta = score.total as f64 - current.1 as f64;
+      if delta < 0.0 && (temperature <= 0.0 || rng.unit() >= (delta / temperature).exp())
+      {
+        continue;
+      }
+
+      current = (candidate, score.total);
+      if score.total > best.score.total {
+        best.board = board;
+        best.score = score;
+      }
+    }
+
+    Ok(best)
+  }
+
+  fn evaluate(
+    &self,
+    state: &State,
+    width: usize,
+  ) -> Result<(SimpleBoard<C>, BoardScore)> {
+    let board = SimpleBoard::from_faces_with_width(&state.faces, width)?;
+
+    let mut words = Vec::new();
+    find_words(&board, self.prefixes, &self.options, &mut words);
+
+    let score = self.scoring.score_words(self.prefixes, &words);
+
+    Ok((board, score))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Optimizer;
+  use crate::{
+    board::{Board, BoardCell},
+    charset::{english_alpha::EnglishAlpha, Charset},
+    generate::{dice::DiceSet, letters::LetterWeights, rng::SplitMix64},
+    scoring::{LengthTable, Scoring},
+    search::{find_words, options::SearchOptions},
+    trie::EnglishAlphaPrefixes,
+  };
+  use std::time::Duration;
+
+  const WORDS: [&str; 10] = [
+    "tea", "eat", "ate", "eta", "seat", "east", "eats", "teas", "sate", "stare",
+  ];
+
+  #[test]
+  fn test_optimize() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&WORDS, 50);
+    let letters = LetterWeights::from_prefixes(&prefixes);
+
+    let optimizer =
+      Optimizer::new(&prefixes, LengthTable::official()).with_iterations(300);
+    let best = optimizer
+      .optimize(&letters, 3, 2, &mut SplitMix64::new(5))
+      .unwrap();
+
+    assert_eq!(best.iterations, 300);
+    assert_eq!((best.board.width(), best.board.height()), (3, 2));
+    assert!(best.score.total > 0);
+
+    let mut words = Vec::new();
+    find_words(&best.board, &prefixes, &SearchOptions::new(), &mut words);
+    assert_eq!(
+      LengthTable::official().score_words(&prefixes, &words),
+      best.score
+    );
+
+    let again = optimizer
+      .optimize(&letters, 3, 2, &mut SplitMix64::new(5))
+      .unwrap();
+    assert_eq!(again.board.code(), best.board.code());
+  }
+
+  #[test]
+  fn test_hill_climb() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&WORDS, 50);
+    let letters = LetterWeights::from_prefixes(&prefixes);
+
+    let start = Optimizer::new(&prefixes, LengthTable::official())
+      .with_iterations(0)
+      .optimize(&letters, 3, 3, &mut SplitMix64::new(8))
+      .unwrap();
+    assert_eq!(start.iterations, 0);
+
+    let climbed = Optimizer::new(&prefixes, LengthTable::official())
+      .with_iterations(200)
+      .with_temperature(0.0)
+      .optimize(&letters, 3, 3, &mut SplitMix64::new(8))
+      .unwrap();
+    assert!(climbed.score.total >= start.score.total);
+
+    let timed = Optimizer::new(&prefixes, LengthTable::official())
+      .with_iterations(usize::MAX)
+      .with_timeout(Duration::from_millis(20))
+      .optimize(&letters, 3, 3, &mut SplitMix64::new(8))
+      .unwrap();
+    assert!(timed.iterations > 0);
+  }
+
+  #[test]
+  fn test_optimize_dice() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&WORDS, 50);
+    let dice = DiceSet::new_boggle();
+
+    let best = Optimizer::new(&prefixes, LengthTable::official())
+      .with_iterations(100)
+      .optimize_dice(&dice, &mut SplitMix64::new(5))
+      .unwrap();
+
+    assert_eq!((best.board.width(), best.board.height()), (4, 4));
+
+    let faces = dice
+      .dice()
+      .iter()
+      .flatten()
+      .filter_map(|face| EnglishAlpha::to_prefix_chars(face))
+      .collect::<Vec<_>>();
+
+    assert!((0..16).all(|index| {
+      let cell = best.board.at(index % 4, index / 4).unwrap();
+      faces.iter().any(|face| face == cell.face())
+    }));
+  }
+}
@@ -0,0 +1,100 @@
+//! Random number generation for board generation.
+
+use std::{
+  collections::hash_map::RandomState,
+  hash::{BuildHasher, Hasher},
+};
+
+/// A source of random numbers.
+pub trait Rng {
+  /// Gets the next random 64 bit number.
+  fn next_u64(&mut self) -> u64;
+
+  /// Gets a random number in `0..n`, or `0` if `n` is `0`.
+  ///
+  /// Numbers in the biased top of the range are rejected rather than wrapped, so every
+  /// result is equally likely.
+  fn below(&mut self, n: usize) -> usize {
+    let n = n as u64;
+    if n == 0 {
+      return 0;
+    }
+
+    let zone = u64::MAX - u64::MAX % n;
+    loop {
+      let value = self.next_u64();
+      if value < zone {
+        return (value % n) as usize;
+      }
+    }
+  }
+
+  /// Gets a random number in `0.0..1.0`.
+  fn unit(&mut self) -> f64 {
+    (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+  }
+
+  /// Shuffles a slice in place with the Fisher-Yates algorithm.
+  fn shuffle<T>(&mut self, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+      items.swap(i, self.below(i + 1));
+    }
+  }
+}
+
+/// The SplitMix64 generator.
+///
+/// Small and fast, with a single 64 bit state, and gives the same numbers on every
+/// platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitMix64 {
+  state: u64,
+}
+
+impl SplitMix64 {
+  /// Create a [SplitMix64] from a seed.
+  pub fn new(seed: u64) -> Self {
+    Self { state: seed }
+  }
+
+  /// Create a [SplitMix64] seeded differently on each call.
+  pub fn from_entropy() -> Self {
+    Self::new(RandomState::new().build_hasher().finish())
+  }
+}
+
+impl Rng for SplitMix64 {
+  fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    let mut z = self.state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Rng, SplitMix64};
+
+  #[test]
+  fn test_split_mix_64() {
+    let mut rng = SplitMix64::new(0);
+
+    assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+    assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+
+    let mut rng = SplitMix64::new(7);
+    assert!((0..100).all(|_| rng.below(6) < 6));
+    assert_eq!(rng.below(0), 0);
+    assert!((0..100)
+      .map(|_| rng.unit())
+      .all(|unit| (0.0..1.0).contains(&unit)));
+
+    let mut items = [1, 2, 3, 4, 5];
+    rng.shuffle(&mut items);
+    items.sort_unstable();
+    assert_eq!(items, [1, 2, 3, 4, 5]);
+  }
+}
@@ -0,0 +1,66 @@
+//! Seeded board generation.
+//!
+//! A seed string always gives the same board on every platform and version: the string
+//! is hashed with 64 bit FNV-1a into the seed of a [SplitMix64] generator, which shuffles
+//! the dice with [super::rng::Rng::shuffle] and rolls each die with
+//! [super::rng::Rng::below]. Changing any of
+//! these steps or the built-in dice changes the boards of existing seeds.
+
+use super::{dice::DiceSet, rng::SplitMix64};
+use crate::{board::simple::SimpleBoard, charset::Charset};
+use eyre::Result;
+
+/// Hashes a seed string with 64 bit FNV-1a.
+pub fn hash_seed(seed: &str) -> u64 {
+  const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+  const PRIME: u64 = 0x0100_0000_01b3;
+
+  seed.bytes().fold(OFFSET, |hash, byte| {
+    (hash ^ byte as u64).wrapping_mul(PRIME)
+  })
+}
+
+/// Create a [SplitMix64] from a seed string.
+pub fn seeded_rng(seed: &str) -> SplitMix64 {
+  SplitMix64::new(hash_seed(seed))
+}
+
+/// Gets the seed string of the daily puzzle of a date, such as `daily-2024-01-31`.
+pub fn daily_seed(year: u16, month: u8, day: u8) -> String {
+  format!("daily-{year:04}-{month:02}-{day:02}")
+}
+
+/// Rolls the board of a seed string with a dice set.
+pub fn seeded_board<C: Charset>(dice: &DiceSet, seed: &str) -> Result<SimpleBoard<C>> {
+  dice.roll(&mut seeded_rng(seed))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{daily_seed, hash_seed, seeded_board};
+  use crate::{board::simple::EnglishAlphaSimpleBoard, generate::dice::DiceSet};
+
+  #[test]
+  fn test_hash_seed() {
+    assert_eq!(hash_seed(""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(hash_seed("a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(daily_seed(2024, 1, 31), "daily-2024-01-31");
+  }
+
+  #[test]
+  fn test_seeded_board() {
+    let board: EnglishAlphaSimpleBoard =
+      seeded_board(&DiceSet::new_boggle(), "daily-2024-01-31").unwrap();
+
+    // Seeds must keep their boards, update this only when breaking them on purpose.
+    assert_eq!(board.code(), "fagbhvltuolioqaw");
+
+    let again: EnglishAlphaSimpleBoard =
+      seeded_board(&DiceSet::new_boggle(), "daily-2024-01-31").unwrap();
+    assert_eq!(again.code(), board.code());
+
+    let other: EnglishAlphaSimpleBoard =
+      seeded_board(&DiceSet::new_boggle(), "daily-2024-02-01").unwrap();
+    assert_ne!(other.code(), board.code());
+  }
+}
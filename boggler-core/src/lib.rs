@@ -2,5 +2,8 @@
 
 pub mod board;
 pub mod charset;
+pub mod game;
+pub mod generate;
+pub mod scoring;
 pub mod search;
 pub mod trie;
@@ -0,0 +1,163 @@
+//! Word length scoring tables.
+
+use super::{read_pairs, Scoring};
+use eyre::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+  fs::File,
+  io::{BufRead, BufReader},
+  path::Path,
+};
+
+/// Points by word length, such as the official boggle rules.
+///
+/// Each step gives its points to words at least as long as the step and shorter than the
+/// next step. Words shorter than the first step score nothing.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "Steps")]
+pub struct LengthTable {
+  steps: Vec<(usize, u32)>,
+}
+
+/// The serialized form of a [LengthTable], deserialized through [LengthTable::new] so
+/// the steps are sorted.
+#[derive(Deserialize)]
+struct Steps {
+  steps: Vec<(usize, u32)>,
+}
+
+impl From<Steps> for LengthTable {
+  fn from(Steps { steps }: Steps) -> Self {
+    Self::new(steps)
+  }
+}
+
+impl LengthTable {
+  /// Create a [LengthTable] from `(length, points)` steps, in any order.
+  ///
+  /// If several steps share a length, the first one supplied is kept.
+  pub fn new(mut steps: Vec<(usize, u32)>) -> Self {
+    steps.sort_by_key(|(len, _)| *len);
+    steps.dedup_by_key(|(len, _)| *len);
+
+    Self { steps }
+  }
+
+  /// The official boggle table: 3 and 4 letters score 1, 5 score 2, 6 score 3, 7 score 5
+  /// and 8 or more score 11.
+  pub fn official() -> Self {
+    Self::new(vec![(3, 1), (5, 2), (6, 3), (7, 5), (8, 11)])
+  }
+
+  /// The big boggle table, which is the official table without 3 letter words.
+  pub fn big_boggle() -> Self {
+    Self::new(vec![(4, 1), (5, 2), (6, 3), (7, 5), (8, 11)])
+  }
+
+  /// Reads a table from a file.
+  ///
+  /// See [LengthTable::from_reader] for the expected format.
+  pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+    Self::from_reader(BufReader::new(File::open(path)?))
+  }
+
+  /// Reads a table with a step on each line, as a length followed by whitespace and the
+  /// points. Blank lines and `#` comments are skipped.
+  pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+    let steps = read_pairs(reader)?
+      .into_iter()
+      .map(|(len, points)| {
+        let len = len
+          .parse()
+          .map_err(|err| eyre::eyre!("invalid word length `{len}`: {err}"))?;
+        let points = points
+          .parse()
+          .map_err(|err| eyre::eyre!("invalid points `{points}`: {err}"))?;
+
+        Ok((len, points))
+      })
+      .collect::<Result<_>>()?;
+
+    Ok(Self::new(steps))
+  }
+
+  /// Gets the shortest word length that scores points.
+  pub fn min_len(&self) -> Option<usize> {
+    self.steps.first().map(|(len, _)| *len)
+  }
+
+  /// Gets the points of a word length.
+  pub fn points(&self, len: usize) -> u32 {
+    self
+      .steps
+      .iter()
+      .rev()
+      .find(|(step, _)| *step <= len)
+      .map_or(0, |(_, points)| *points)
+  }
+}
+
+impl Default for LengthTable {
+  fn default() -> Self {
+    Self::official()
+  }
+}
+
+impl Scoring for LengthTable {
+  fn score(&self, word: &str) -> u32 {
+    self.points(word.chars().count())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::LengthTable;
+  use crate::scoring::Scoring;
+
+  #[test]
+  fn test_official() {
+    let table = LengthTable::official();
+    let points = (0..=10).map(|len| table.points(len)).collect::<Vec<_>>();
+
+    assert_eq!(points, [0, 0, 0, 1, 1, 2, 3, 5, 11, 11, 11]);
+    assert_eq!(table.score("quit"), 1);
+    assert_eq!(LengthTable::big_boggle().score("tea"), 0);
+    assert_eq!(LengthTable::big_boggle().score("teas"), 1);
+  }
+
+  #[test]
+  fn test_new() {
+    let table = LengthTable::new(vec![(3, 1), (4, 9), (3, 7), (3, 2), (3, 5)]);
+    assert_eq!(table, LengthTable::new(vec![(3, 1), (4, 9)]));
+
+    let table = LengthTable::new(vec![(4, 9), (3, 7), (3, 1)]);
+    assert_eq!(table.points(3), 7);
+  }
+
+  #[test]
+  fn test_from_reader() {
+    let config = "# length points\n4 2\n\n2 1 # short words\n";
+    let table = LengthTable::from_reader(config.as_bytes()).unwrap();
+
+    assert_eq!(table, LengthTable::new(vec![(2, 1), (4, 2)]));
+    assert_eq!(table.min_len(), Some(2));
+    assert_eq!(table.score("teas"), 2);
+
+    assert!(LengthTable::from_reader("4".as_bytes()).is_err());
+    assert!(LengthTable::from_reader("four 2".as_bytes()).is_err());
+  }
+
+  #[test]
+  fn test_deserialize() {
+    let table: LengthTable =
+      serde_json::from_str(r#"{"steps": [[8, 11], [3, 1]]}"#).unwrap();
+
+    assert_eq!(table.points(8), 11);
+    assert_eq!(table.points(4), 1);
+    assert_eq!(table, LengthTable::new(vec![(3, 1), (8, 11)]));
+
+    let json = serde_json::to_string(&LengthTable::official()).unwrap();
+    let table: LengthTable = serde_json::from_str(&json).unwrap();
+    assert_eq!(table, LengthTable::official());
+  }
+}
@@ -0,0 +1,108 @@
+//! Per letter scoring.
+
+use super::{read_pairs, Scoring};
+use eyre::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+  collections::HashMap,
+  fs::File,
+  io::{BufRead, BufReader},
+  path::Path,
+};
+
+/// The english scrabble letter values, from `a` to `z`.
+const SCRABBLE: [u32; 26] = [
+  1, 3, 3, 2, 1, 4, 2, 4, 1, 8, 5, 1, 3, 1, 1, 3, 10, 1, 1, 1, 1, 4, 4, 8, 4, 10,
+];
+
+/// Points for each letter of a word, such as scrabble letter values.
+///
+/// Letters are compared in lowercase and letters without a value score nothing.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct LetterValues {
+  values: HashMap<char, u32>,
+}
+
+impl LetterValues {
+  /// Create [LetterValues] from the points of each letter.
+  pub fn new(values: HashMap<char, u32>) -> Self {
+    let values = values
+      .into_iter()
+      .flat_map(|(ch, points)| ch.to_lowercase().map(move |ch| (ch, points)))
+      .collect();
+
+    Self { values }
+  }
+
+  /// The english scrabble letter values.
+  pub fn scrabble() -> Self {
+    Self::new(('a'..='z').zip(SCRABBLE).collect())
+  }
+
+  /// Reads letter values from a file.
+  ///
+  /// See [LetterValues::from_reader] for the expected format.
+  pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+    Self::from_reader(BufReader::new(File::open(path)?))
+  }
+
+  /// Reads letter values with a letter on each line, followed by whitespace and the
+  /// points. Blank lines and `#` comments are skipped.
+  pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+    let values = read_pairs(reader)?
+      .into_iter()
+      .map(|(letter, points)| {
+        let mut chars = letter.chars();
+        let ch = match (chars.next(), chars.next()) {
+          (Some(ch), None) => ch,
+          _ => eyre::bail!("expected a single letter, found `{letter}`"),
+        };
+
+        let points = points
+          .parse()
+          .map_err(|err| eyre::eyre!("invalid points `{points}`: {err}"))?;
+
+        Ok((ch, points))
+      })
+      .collect::<Result<_>>()?;
+
+    Ok(Self::new(values))
+  }
+
+  /// Gets the points of a letter.
+  pub fn points(&self, ch: char) -> u32 {
+    ch.to_lowercase()
+      .map(|ch| self.values.get(&ch).copied().unwrap_or(0))
+      .sum()
+  }
+}
+
+impl Scoring for LetterValues {
+  fn score(&self, word: &str) -> u32 {
+    word.chars().map(|ch| self.points(ch)).sum()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::LetterValues;
+  use crate::scoring::Scoring;
+
+  #[test]
+  fn test_scrabble() {
+    let values = LetterValues::scrabble();
+
+    assert_eq!(values.score("quiz"), 22);
+    assert_eq!(values.score("Tea"), 3);
+    assert_eq!(values.score("t-a"), 2);
+  }
+
+  #[test]
+  fn test_from_reader() {
+    let values = LetterValues::from_reader("A 2\nz 5 # rare\n".as_bytes()).unwrap();
+
+    assert_eq!(values.score("zaza"), 14);
+    assert_eq!(values.score("tea"), 2);
+    assert!(LetterValues::from_reader("ab 2".as_bytes()).is_err());
+  }
+}
@@ -0,0 +1,102 @@
+//! Word and board scoring.
+
+pub mod length;
+pub mod letters;
+
+pub use self::{length::LengthTable, letters::LetterValues};
+
+use crate::{charset::Charset, trie::Prefixes};
+use eyre::Result;
+use std::io::BufRead;
+
+/// A rule giving points to words.
+pub trait Scoring {
+  /// Gets the points of a word, spelled as in a canonical word table.
+  fn score(&self, word: &str) -> u32;
+
+  /// Scores the words found on a board, such as by [crate::search::find_words].
+  ///
+  /// Word ids are deduplicated, so a word that can be traced more than once is scored
+  /// once.
+  fn score_words<C: Charset, M>(
+    &self,
+    prefixes: &Prefixes<C, M>,
+    words: &[usize],
+  ) -> BoardScore {
+    let mut ids = words.to_vec();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let words = ids
+      .into_iter()
+      .filter_map(|id| Some((id, self.score(prefixes.word(id)?))))
+      .collect::<Vec<_>>();
+
+    let total = words.iter().map(|(_, points)| points).sum();
+
+    BoardScore { words, total }
+  }
+}
+
+/// The points of the words found on a board.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BoardScore {
+  /// The word ids and their points, ordered by word id.
+  pub words: Vec<(usize, u32)>,
+  /// The sum of the points of every word.
+  pub total: u32,
+}
+
+/// Reads the lines of a scoring config, skipping blank lines and `#` comments, as pairs
+/// of whitespace separated columns.
+fn read_pairs<R: BufRead>(reader: R) -> Result<Vec<(String, String)>> {
+  let mut pairs = Vec::new();
+
+  for line in reader.lines() {
+    let line = line?;
+    let line = line.split('#').next().unwrap_or_default().trim();
+    if line.is_empty() {
+      continue;
+    }
+
+    let mut columns = line.split_whitespace();
+    match (columns.next(), columns.next(), columns.next()) {
+      (Some(key), Some(value), None) => pairs.push((key.to_string(), value.to_string())),
+      _ => eyre::bail!("expected two columns in scoring line `{line}`"),
+    }
+  }
+
+  Ok(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{BoardScore, LengthTable, Scoring};
+  use crate::{
+    board::simple::EnglishAlphaSimpleBoard,
+    search::{find_words, options::SearchOptions},
+    trie::EnglishAlphaPrefixes,
+  };
+
+  #[test]
+  fn test_score_words() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&["tea", "eat", "texas", "axe"], 50);
+
+    // t e z
+    // a x z
+    // s z z
+    let board = EnglishAlphaSimpleBoard::new("tezaxzszz").unwrap();
+    let mut found = Vec::new();
+    find_words(&board, &prefixes, &SearchOptions::new(), &mut found);
+
+    let score = LengthTable::official().score_words(&prefixes, &found);
+
+    assert_eq!(
+      score,
+      BoardScore {
+        words: vec![(0, 1), (1, 1), (2, 1), (3, 2)],
+        total: 5,
+      }
+    );
+  }
+}
@@ -0,0 +1,256 @@
+//! Letter rack anagram search.
+
+use crate::{
+  charset::Charset,
+  trie::{PrefixNodeRef, Prefixes},
+};
+use eyre::Result;
+use std::marker::PhantomData;
+
+/// The character used for a blank in a rack string.
+pub const BLANK: char = '?';
+
+/// A multiset of charset letters and blanks that may stand in for any letter, with one
+/// tile for each letter so that `qu` is two tiles.
+#[derive(Debug)]
+pub struct Rack<C: Charset> {
+  counts: Vec<usize>,
+  blanks: usize,
+  _phantom: PhantomData<C>,
+}
+
+impl<C: Charset> Clone for Rack<C> {
+  fn clone(&self) -> Self {
+    Self {
+      counts: self.counts.clone(),
+      blanks: self.blanks,
+      _phantom: Default::default(),
+    }
+  }
+}
+
+impl<C: Charset> Rack<C> {
+  /// Create a [Rack] from a string of letters where [BLANK] is a blank.
+  pub fn new(rack: &str) -> Result<Self> {
+    let mut counts = vec![0; C::LEN as usize];
+    let mut blanks = 0;
+
+    for ch in rack.chars() {
+      if ch == BLANK {
+        blanks += 1;
+        continue;
+      }
+
+      let ch = C::to_prefix_char(ch)
+        .ok_or(eyre::eyre!("rack contains invalid character `{ch}`"))?;
+
+      counts[ch as usize] += 1;
+    }
+
+    Ok(Self {
+      counts,
+      blanks,
+      _phantom: Default::default(),
+    })
+  }
+
+  /// Gets the number of times the letter of a prefix character is in the rack.
+  pub fn count(&self, ch: u8) -> usize {
+    self.counts.get(ch as usize).copied().unwrap_or(0)
+  }
+
+  /// Gets the number of blanks in the rack.
+  pub fn blanks(&self) -> usize {
+    self.blanks
+  }
+
+  /// Gets the number of letters and blanks in the rack.
+  pub fn len(&self) -> usize {
+    self.counts.iter().sum::<usize>() + self.blanks
+  }
+
+  /// Determines if the rack has no letters or blanks.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Determines if a word can be spelled with the rack, letter by letter, using every
+  /// letter and blank if `use_all`.
+  fn spells(&self, word: &str, use_all: bool) -> bool {
+    let mut counts = self.counts.clone();
+    let mut blanks = self.blanks;
+    let mut len = 0;
+
+    for ch in word.chars() {
+      let ch = match C::to_prefix_char(ch) {
+        Some(ch) => ch as usize,
+        None => return false,
+      };
+
+      match counts.get_mut(ch) {
+        Some(count) if *count > 0 => *count -= 1,
+        _ if blanks > 0 => blanks -= 1,
+        _ => return false,
+      }
+
+      len += 1;
+    }
+
+    !use_all || len == self.len()
+  }
+}
+
+/// Finds the words that can be made from some or all of the letters in a rack.
+pub fn find_anagrams<C: Charset, M>(
+  rack: &Rack<C>,
+  prefixes: &Prefixes<C, M>,
+  words: &mut Vec<usize>,
+) {
+  find(rack, prefixes, false, words);
+}
+
+/// Finds the words that use every letter in a rack.
+pub fn find_full_anagrams<C: Charset, M>(
+  rack: &Rack<C>,
+  prefixes: &Prefixes<C, M>,
+  words: &mut Vec<usize>,
+) {
+  find(rack, prefixes, true, words);
+}
+
+fn find<C: Charset, M>(
+  rack: &Rack<C>,
+  prefixes: &Prefixes<C, M>,
+  use_all: bool,
+  words: &mut Vec<usize>,
+) {
+  if let Some(root) = prefixes.root() {
+    let mut tiles = rack.clone();
+    let remaining = rack.len();
+
+    find_at(&root, prefixes, rack, &mut tiles, remaining, use_all, words);
+  }
+}
+
+/// Walks the prefixes that the remaining tiles can start, taking one tile for the first
+/// letter of each prefix character, and keeps the words whose spelling the whole rack
+/// spells.
+fn find_at<C: Charset, M>(
+  node: &PrefixNodeRef<C, M>,
+  prefixes: &Prefixes<C, M>,
+  rack: &Rack<C>,
+  tiles: &mut Rack<C>,
+  remaining: usize,
+  use_all: bool,
+  words: &mut Vec<usize>,
+) {
+  for (ch, child) in node.children() {
+    let ch = ch as usize;
+    // A letter is always used before a blank, as a blank can stand in for any letter
+    // later in the word.
+    let blank = tiles.counts[ch] == 0;
+    let counter = match blank {
+      false => &mut tiles.counts[ch],
+      true if tiles.blanks > 0 => &mut tiles.blanks,
+      true => continue,
+    };
+
+    *counter -= 1;
+
+    if let Some(word) = child.word() {
+      let spelling = prefixes.word(word).unwrap_or_default();
+      if rack.spells(spelling, use_all) {
+        words.push(word);
+      }
+    }
+
+    if remaining > 1 {
+      find_at(&child, prefixes, rack, tiles, remaining - 1, use_all, words);
+    }
+
+    match blank {
+      false => tiles.counts[ch] += 1,
+      true => tiles.blanks += 1,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{find_anagrams, find_full_anagrams, Rack};
+  use crate::{charset::english_alpha::EnglishAlpha, trie::EnglishAlphaPrefixes};
+
+  fn words(prefixes: &EnglishAlphaPrefixes, ids: &[usize]) -> Vec<String> {
+    ids
+      .iter()
+      .map(|id| prefixes.word(*id).unwrap().to_string())
+      .collect()
+  }
+
+  #[test]
+  fn test_rack() {
+    let rack = Rack::<EnglishAlpha>::new("TeeQu??").unwrap();
+
+    assert_eq!(rack.count(4), 2);
+    assert_eq!(rack.count(16), 1);
+    assert_eq!(rack.count(20), 1);
+    assert_eq!(rack.count(19), 1);
+    assert_eq!(rack.blanks(), 2);
+    assert_eq!(rack.len(), 7);
+    assert!(Rack::<EnglishAlpha>::new("te-a").is_err());
+  }
+
+  #[test]
+  fn test_find_anagrams() {
+    let list = [
+      "ate", "eat", "tea", "teat", "sea", "eta", "tee", "quit", "tat",
+    ];
+    let prefixes = EnglishAlphaPrefixes::from_words(&list, 50);
+
+    let mut found = Vec::new();
+    find_anagrams(&Rack::new("teat").unwrap(), &prefixes, &mut found);
+    assert_eq!(
+      words(&prefixes, &found),
+      ["ate", "eat", "eta", "tat", "tea", "teat"]
+    );
+
+    let mut found = Vec::new();
+    find_anagrams(&Rack::new("ea?").unwrap(), &prefixes, &mut found);
+    assert_eq!(
+      words(&prefixes, &found),
+      ["ate", "eat", "eta", "sea", "tea"]
+    );
+
+    let mut found = Vec::new();
+    find_anagrams(&Rack::new("tiqu").unwrap(), &prefixes, &mut found);
+    assert_eq!(words(&prefixes, &found), ["quit"]);
+
+    let mut found = Vec::new();
+    find_anagrams(&Rack::new("tiq").unwrap(), &prefixes, &mut found);
+    assert!(found.is_empty());
+
+    let mut found = Vec::new();
+    find_anagrams(&Rack::new("tiq?").unwrap(), &prefixes, &mut found);
+    assert_eq!(words(&prefixes, &found), ["quit"]);
+  }
+
+  #[test]
+  fn test_find_full_anagrams() {
+    let list = ["ate", "eat", "tea", "teat", "eta", "at"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&list, 50);
+
+    let mut found = Vec::new();
+    find_full_anagrams(&Rack::new("tae").unwrap(), &prefixes, &mut found);
+    assert_eq!(words(&prefixes, &found), ["ate", "eat", "eta", "tea"]);
+
+    let mut found = Vec::new();
+    find_full_anagrams(&Rack::new("t?ta").unwrap(), &prefixes, &mut found);
+    assert_eq!(words(&prefixes, &found), ["teat"]);
+
+    let prefixes = EnglishAlphaPrefixes::from_words(&["quit", "quits"], 50);
+
+    let mut found = Vec::new();
+    find_full_anagrams(&Rack::new("tiuqs").unwrap(), &prefixes, &mut found);
+    assert_eq!(words(&prefixes, &found), ["quits"]);
+  }
+}
@@ -0,0 +1,216 @@
+//! Batch boggle board search.
+
+use super::{
+  iter::{FindWords, SearchBuffers},
+  options::SearchOptions,
+};
+use crate::{board::Board, charset::Charset, trie::Prefixes};
+use std::{
+  sync::{mpsc, Mutex},
+  thread,
+};
+
+/// Buffers reused between board searches, so solving another board refills the cell
+/// buffers and word list instead of allocating new ones.
+#[derive(Debug, Default, Clone)]
+pub struct Scratch {
+  buffers: SearchBuffers,
+  words: Vec<usize>,
+}
+
+impl Scratch {
+  /// Create a [Scratch].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Finds the sorted and deduplicated ids of the dictionary words that can be traced on
+  /// a board.
+  pub fn solve<'a, 'b: 'a, B: Board<'a>, C: Charset, M>(
+    &mut self,
+    board: &'b B,
+    prefixes: &Prefixes<C, M>,
+    options: &SearchOptions,
+  ) -> &[usize] {
+    let buffers = std::mem::take(&mut self.buffers);
+    let mut words = FindWords::with_buffers(board, prefixes, options, buffers);
+
+    self.words.clear();
+    self.words.extend(&mut words);
+    self.buffers = words.into_buffers();
+
+    self.words.sort_unstable();
+    self.words.dedup();
+
+    &self.words
+  }
+}
+
+/// The words found on a board of a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardResult {
+  /// The position of the board in the batch.
+  pub index: usize,
+  /// The sorted and deduplicated ids of the words found.
+  pub words: Vec<usize>,
+}
+
+/// Summary statistics of a batch.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BatchStats {
+  /// The number of boards solved.
+  pub boards: usize,
+  /// The number of boards without words.
+  pub empty_boards: usize,
+  /// The sum of the number of words found on each board.
+  pub total_words: usize,
+  /// The fewest words found on a board.
+  pub min_words: usize,
+  /// The most words found on a board.
+  pub max_words: usize,
+}
+
+impl BatchStats {
+  /// Adds the words found on a board to the statistics.
+  pub fn record(&mut self, words: usize) {
+    self.min_words = match self.boards {
+      0 => words,
+      _ => self.min_words.min(words),
+    };
+
+    self.max_words = self.max_words.max(words);
+    self.total_words += words;
+    self.empty_boards += usize::from(words == 0);
+    self.boards += 1;
+  }
+
+  /// Gets the mean number of words found on a board.
+  pub fn mean_words(&self) -> f64 {
+    match self.boards {
+      0 => 0.0,
+      boards => self.total_words as f64 / boards as f64,
+    }
+  }
+}
+
+/// Solves a batch of boards across `threads` threads, or one per available core if
+/// `threads` is `0`.
+///
+/// Boards are pulled from the iterator as threads become free, each thread reusing its
+/// own [Scratch] and copying only the words found into each result. `on_result` is
+/// called on the calling thread as each board is solved. Results arrive in completion
+/// order, use [BoardResult::index] to restore the order of the batch.
+pub fn solve_batch<I, B, C, M, F>(
+  boards: I,
+  prefixes: &Prefixes<C, M>,
+  options: &SearchOptions,
+  threads: usize,
+  mut on_result: F,
+) -> BatchStats
+where
+  I: Iterator<Item = B> + Send,
+  B: for<'board> Board<'board> + Send,
+  C: Charset,
+  Prefixes<C, M>: Sync,
+  F: FnMut(BoardResult),
+{
+  let threads = match threads {
+    0 => thread::available_parallelism().map_or(1, |threads| threads.get()),
+    threads => threads,
+  };
+
+  let boards = Mutex::new(boards.enumerate());
+  let mut stats = BatchStats::default();
+
+  thread::scope(|scope| {
+    // A bounded channel stops solving from running far ahead of `on_result`.
+    let (tx, rx) = mpsc::sync_channel(threads * 2);
+
+    for _ in 0..threads {
+      let tx = tx.clone();
+      let boards = &boards;
+
+      scope.spawn(move || {
+        let mut scratch = Scratch::new();
+
+        loop {
+          let next = boards.lock().expect("board iterator poisoned").next();
+          let (index, board) = match next {
+            Some(next) => next,
+            None => break,
+          };
+
+          let words = scratch.solve(&board, prefixes, options).to_vec();
+          if tx.send(BoardResult { index, words }).is_err() {
+            break;
+          }
+        }
+      });
+    }
+
+    drop(tx);
+
+    for result in rx {
+      stats.record(result.words.len());
+      on_result(result);
+    }
+  });
+
+  stats
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{solve_batch, BatchStats, Scratch};
+  use crate::{
+    board::simple::EnglishAlphaSimpleBoard, search::options::SearchOptions,
+    trie::EnglishAlphaPrefixes,
+  };
+
+  #[test]
+  fn test_scratch() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&["tea", "eat", "tax", "axe"], 50);
+    let options = SearchOptions::new();
+    let mut scratch = Scratch::new();
+
+    let board = EnglishAlphaSimpleBoard::new("teax").unwrap();
+    assert_eq!(scratch.solve(&board, &prefixes, &options), &[0, 1, 2, 3]);
+
+    let board = EnglishAlphaSimpleBoard::new("teazzzzzz").unwrap();
+    assert_eq!(scratch.solve(&board, &prefixes, &options), &[3]);
+  }
+
+  #[test]
+  fn test_solve_batch() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&["tea", "eat", "tax", "axe"], 50);
+    let boards = ["teax", "zzzz", "teazzzzzz", "atex"]
+      .into_iter()
+      .map(|board| EnglishAlphaSimpleBoard::new(board).unwrap());
+
+    let options = SearchOptions::new();
+    let mut results = Vec::new();
+    let stats = solve_batch(boards, &prefixes, &options, 2, |result| {
+      results.push(result)
+    });
+
+    results.sort_by_key(|result| result.index);
+
+    let words = results
+      .into_iter()
+      .map(|result| result.words)
+      .collect::<Vec<_>>();
+
+    assert_eq!(words, [vec![0, 1, 2, 3], vec![], vec![3], vec![0, 1, 2, 3]]);
+    assert_eq!(
+      stats,
+      BatchStats {
+        boards: 4,
+        empty_boards: 1,
+        total_words: 9,
+        min_words: 0,
+        max_words: 4,
+      }
+    );
+    assert_eq!(stats.mean_words(), 2.25);
+  }
+}
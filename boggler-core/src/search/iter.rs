@@ -0,0 +1,400 @@
+//! Lazy boggle board search.
+
+use super::{
+  limits::{SearchLimits, Truncation},
+  options::{CellReuse, SearchOptions},
+  pattern::Pattern,
+};
+use crate::{
+  board::{Board, BoardCell},
+  charset::Charset,
+  trie::{PrefixNodeRef, Prefixes},
+};
+
+/// A lazy iterator over the ids of the dictionary words that can be traced on a board.
+///
+/// Words are yielded as they are found using an explicit stack rather than recursion, so
+/// a search can be stopped early and memory is bounded by the longest word.
+pub struct FindWords<'board, 'prefixes, B: Board<'board>, C: Charset, M = ()> {
+  board: &'board B,
+  prefixes: &'prefixes Prefixes<C, M>,
+  root: Option<PrefixNodeRef<'prefixes, C, M>>,
+  starts: Vec<(u16, u16)>,
+  next_start: usize,
+  visited: Vec<bool>,
+  stack: Vec<Frame<'board, 'prefixes, B, C, M>>,
+  min_len: usize,
+  max_len: Option<usize>,
+  reuse: CellReuse,
+  forbidden: Vec<bool>,
+  ends: Vec<bool>,
+  any_end: bool,
+  required: Vec<Option<usize>>,
+  required_left: usize,
+  pattern: Option<Pattern>,
+  limits: SearchLimits,
+  found: usize,
+  steps: usize,
+  truncated: Option<Truncation>,
+}
+
+/// The cell buffers of a [FindWords] search, kept between searches so that searching
+/// another board fills them again instead of allocating new ones.
+#[derive(Debug, Default, Clone)]
+pub struct SearchBuffers {
+  starts: Vec<(u16, u16)>,
+  visited: Vec<bool>,
+  forbidden: Vec<bool>,
+  ends: Vec<bool>,
+  required: Vec<Option<usize>>,
+}
+
+/// The number of cells entered between deadline and cancellation checks.
+const CHECK_INTERVAL: usize = 256;
+
+/// A board cell on the current search path.
+struct Frame<'board, 'prefixes, B: Board<'board>, C: Charset, M> {
+  index: usize,
+  /// The number of characters on the path up to and including this cell.
+  depth: usize,
+  /// The number of letters on the path up to and including this cell, if searching with
+  /// a pattern.
+  position: usize,
+  cell: B::Cell,
+  node: PrefixNodeRef<'prefixes, C, M>,
+  neighbors: B::Neighbors,
+}
+
+impl<'board, 'prefixes, B: Board<'board>, C: Charset, M>
+  FindWords<'board, 'prefixes, B, C, M>
+{
+  /// Create a [FindWords] iterator searching from every board cell with the default
+  /// [SearchOptions].
+  pub fn new(board: &'board B, prefixes: &'prefixes Prefixes<C, M>) -> Self {
+    Self::with_buffers(
+      board,
+      prefixes,
+      &SearchOptions::default(),
+      SearchBuffers::default(),
+    )
+  }
+
+  /// Create a [FindWords] iterator searching with supplied options, filling buffers
+  /// such as ones returned by [FindWords::into_buffers].
+  pub fn with_buffers(
+    board: &'board B,
+    prefixes: &'prefixes Prefixes<C, M>,
+    options: &SearchOptions,
+    buffers: SearchBuffers,
+  ) -> Self {
+    let SearchBuffers {
+      mut starts,
+      mut visited,
+      mut forbidden,
+      mut ends,
+      mut required,
+    } = buffers;
+
+    starts.clear();
+    match &options.starts {
+      Some(cells) => starts.extend_from_slice(cells),
+      None => starts.extend(all_cells(board)),
+    }
+
+    visited.clear();
+    visited.resize(board.cell_count(), false);
+
+    fill_mask(board, &mut forbidden, &options.forbidden);
+    fill_mask(
+      board,
+      &mut ends,
+      options.ends.as_deref().unwrap_or_default(),
+    );
+
+    required.clear();
+    required.resize(board.cell_count(), None);
+
+    // Required cells off the board are never reached, so no word is found.
+    let mut required_left = 0;
+    for &(x, y) in &options.required {
+      match is_on_board(board, x, y) {
+        true => required[board.index(x, y)] = Some(0),
+        false => required_left = 1,
+      }
+    }
+
+    required_left += required
+      .iter()
+      .filter(|required| required.is_some())
+      .count();
+
+    Self {
+      board,
+      prefixes,
+      root: prefixes.root(),
+      starts,
+      next_start: 0,
+      visited,
+      stack: Vec::new(),
+      min_len: options.min_len,
+      max_len: options.max_len,
+      reuse: options.reuse,
+      forbidden,
+      ends,
+      any_end: options.ends.is_none(),
+      required,
+      required_left,
+      pattern: options.pattern.clone(),
+      limits: options.limits.clone(),
+      found: 0,
+      steps: 0,
+      truncated: None,
+    }
+  }
+
+  /// Get a [FindWords] searching with supplied options.
+  pub fn with_options(self, options: SearchOptions) -> Self {
+    let (board, prefixes) = (self.board, self.prefixes);
+
+    Self::with_buffers(board, prefixes, &options, self.into_buffers())
+  }
+
+  /// Gets the reason the search stopped early, if it has.
+  pub fn truncated(&self) -> Option<Truncation> {
+    self.truncated
+  }
+
+  /// Gets the cell buffers so they can be reused by [FindWords::with_buffers].
+  pub fn into_buffers(self) -> SearchBuffers {
+    SearchBuffers {
+      starts: self.starts,
+      visited: self.visited,
+      forbidden: self.forbidden,
+      ends: self.ends,
+      required: self.required,
+    }
+  }
+
+  /// Moves onto a cell from a prefix node, returning the word ending at the cell if any.
+  ///
+  /// Does nothing if the cell is forbidden or cannot be reused, the path already has
+  /// the most cells allowed, the path cannot reach the remaining required cells within
+  /// the longest word, the pattern does not allow the cell letters at their
+  /// positions, or the prefix cannot continue with the cell characters.
+  fn enter(
+    &mut self,
+    cell: B::Cell,
+    node: PrefixNodeRef<'prefixes, C, M>,
+  ) -> Option<usize> {
+    let index = self.board.index(cell.x(), cell.y());
+    let revisit = self.visited[index] && self.reuse == CellReuse::Once;
+    let too_deep = self.limits.max_depth == Some(self.stack.len());
+    if revisit || too_deep || self.forbidden[index] {
+      return None;
+    }
+
+    let depth = self.stack.last().map_or(0, |frame| frame.depth);
+    let len = depth + cell.face().len();
+
+    if let Some(max_len) = self.max_len {
+      // Every character is at least one letter, and each required cell not yet on the
+      // path needs at least one more.
+      let required_left =
+        self.required_left - usize::from(self.required.get(index) == Some(&Some(0)));
+
+      if len > max_len || required_left > max_len - len {
+        return None;
+      }
+    }
+
+    let mut position = self.stack.last().map_or(0, |frame| frame.position);
+    if let Some(pattern) = &self.pattern {
+      for &letter in cell.face().iter().flat_map(|&ch| pattern.letters(ch)) {
+        if !pattern.allows(position, letter) {
+          return None;
+        }
+
+        position += 1;
+      }
+    }
+
+    let node = node.descend(cell.face())?;
+
+    self.visited[index] = true;
+    if let Some(Some(visits)) = self.required.get_mut(index) {
+      self.required_left -= usize::from(*visits == 0);
+      *visits += 1;
+    }
+
+    let neighbors = self.board.neighbors(cell.x(), cell.y());
+    self.stack.push(Frame {
+      index,
+      depth: len,
+      position,
+      cell,
+      node,
+      neighbors,
+    });
+
+    let word = node.word()?;
+    let ends_here = self.any_end || self.ends[index];
+    let matches = self.pattern.as_ref().is_none_or(|pattern| {
+      let letters = self
+        .stack
+        .iter()
+        .flat_map(|frame| frame.cell.face())
+        .flat_map(|&ch| pattern.letters(ch));
+
+      pattern.accepts(position, letters.copied())
+    });
+
+    let letters = self
+      .prefixes
+      .word(word)
+      .map_or(len, |word| word.chars().count());
+    let long_enough =
+      letters >= self.min_len && self.max_len.is_none_or(|max| letters <= max);
+
+    let accepted = long_enough && self.required_left == 0 && ends_here && matches;
+    accepted.then_some(word)
+  }
+
+  /// Moves back off the last cell of the path.
+  fn leave(&mut self) {
+    if let Some(frame) = self.stack.pop() {
+      self.visited[frame.index] = false;
+      if let Some(Some(visits)) = self.required.get_mut(frame.index) {
+        *visits -= 1;
+        self.required_left += usize::from(*visits == 0);
+      }
+    }
+  }
+
+  /// Finds the next word, or nothing if the search is over or a limit was reached.
+  fn find_next(&mut self) -> Option<usize> {
+    let root = self.root?;
+
+    loop {
+      if self.steps.is_multiple_of(CHECK_INTERVAL) {
+        self.truncated = self.limits.check();
+        if self.truncated.is_some() {
+          return None;
+        }
+      }
+
+      self.steps += 1;
+
+      let word = match self.stack.last_mut() {
+        Some(frame) => match frame.neighbors.next() {
+          Some(neighbor) => {
+            let node = frame.node;
+            self.enter(neighbor, node)
+          }
+          None => {
+            self.leave();
+            None
+          }
+        },
+        None => {
+          let (x, y) = *self.starts.get(self.next_start)?;
+          self.next_start += 1;
+
+          match self.board.at(x, y) {
+            Some(cell) => self.enter(cell, root),
+            None => None,
+          }
+        }
+      };
+
+      if word.is_some() {
+        return word;
+      }
+    }
+  }
+}
+
+/// Iterates over the coordinates of every board cell, column by column.
+pub(super) fn all_cells<'board, B: Board<'board>>(
+  board: &B,
+) -> impl Iterator<Item = (u16, u16)> {
+  let (width, height) = (board.width() as u16, board.height() as u16);
+
+  (0..width).flat_map(move |x| (0..height).map(move |y| (x, y)))
+}
+
+/// Determines if coordinates are on a board.
+fn is_on_board<'board, B: Board<'board>>(board: &B, x: u16, y: u16) -> bool {
+  (x as usize) < board.width() && (y as usize) < board.height()
+}
+
+/// Fills a buffer with a mask of the board cells in `cells`, ignoring cells off the
+/// board.
+fn fill_mask<'board, B: Board<'board>>(
+  board: &B,
+  mask: &mut Vec<bool>,
+  cells: &[(u16, u16)],
+) {
+  mask.clear();
+  mask.resize(board.cell_count(), false);
+
+  for &(x, y) in cells {
+    if is_on_board(board, x, y) {
+      mask[board.index(x, y)] = true;
+    }
+  }
+}
+
+impl<'board, 'prefixes, B: Board<'board>, C: Charset, M> Iterator
+  for FindWords<'board, 'prefixes, B, C, M>
+{
+  type Item = usize;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.truncated.is_some() {
+      return None;
+    }
+
+    let word = self.find_next()?;
+
+    // A word past the maximum number of results is held back to mark the search as
+    // truncated, so a search finding exactly that many words is not.
+    if self.limits.max_results == Some(self.found) {
+      self.truncated = Some(Truncation::MaxResults);
+      return None;
+    }
+
+    self.found += 1;
+    Some(word)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::FindWords;
+  use crate::{
+    board::simple::EnglishAlphaSimpleBoard, search::options::SearchOptions,
+    trie::EnglishAlphaPrefixes,
+  };
+
+  #[test]
+  fn test_find_words_lazily() {
+    let words = ["tea", "eat", "ate", "eta", "axe", "tax", "taxa"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+    let board = EnglishAlphaSimpleBoard::new("teax").unwrap();
+
+    let first = FindWords::new(&board, &prefixes)
+      .take(2)
+      .map(|id| prefixes.word(id).unwrap())
+      .collect::<Vec<_>>();
+
+    assert_eq!(first, ["tax", "tea"]);
+
+    let options = SearchOptions::new().with_starts(vec![(0, 1), (9, 9)]);
+    let from_a = FindWords::new(&board, &prefixes)
+      .with_options(options)
+      .map(|id| prefixes.word(id).unwrap())
+      .collect::<Vec<_>>();
+
+    assert_eq!(from_a, ["ate", "axe"]);
+  }
+}
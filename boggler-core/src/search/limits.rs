@@ -0,0 +1,121 @@
+//! Boggle board search limits.
+
+use std::{
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  time::{Duration, Instant},
+};
+
+/// A flag shared between threads to cancel searches.
+#[derive(Debug, Default, Clone)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+  /// Create a [CancelToken].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Cancels every search using this token or a clone of it.
+  pub fn cancel(&self) {
+    self.0.store(true, Ordering::Relaxed);
+  }
+
+  /// Determines if the token has been cancelled.
+  pub fn is_cancelled(&self) -> bool {
+    self.0.load(Ordering::Relaxed)
+  }
+}
+
+/// Limits on how long a search may run and how much it may find.
+#[derive(Debug, Default, Clone)]
+pub struct SearchLimits {
+  /// The instant after which the search stops.
+  pub deadline: Option<Instant>,
+  /// The most word ids the search yields, the search is only truncated if it finds more.
+  pub max_results: Option<usize>,
+  /// The most board cells in a path, longer paths are not searched.
+  ///
+  /// Unlike [super::options::SearchOptions::max_len] this counts cells, so a path over
+  /// multi-letter faces is shorter than its word.
+  pub max_depth: Option<usize>,
+  /// A token that stops the search when cancelled.
+  pub cancel: Option<CancelToken>,
+}
+
+impl SearchLimits {
+  /// Create [SearchLimits] without any limits.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Get [SearchLimits] with a deadline.
+  pub fn with_deadline(mut self, deadline: Instant) -> Self {
+    self.deadline = Some(deadline);
+    self
+  }
+
+  /// Get [SearchLimits] with a deadline of `timeout` from now.
+  pub fn with_timeout(self, timeout: Duration) -> Self {
+    self.with_deadline(Instant::now() + timeout)
+  }
+
+  /// Get [SearchLimits] with a maximum number of results.
+  pub fn with_max_results(mut self, max_results: usize) -> Self {
+    self.max_results = Some(max_results);
+    self
+  }
+
+  /// Get [SearchLimits] with a maximum path length.
+  pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+    self.max_depth = Some(max_depth);
+    self
+  }
+
+  /// Get [SearchLimits] with a cancellation token.
+  pub fn with_cancel(mut self, cancel: CancelToken) -> Self {
+    self.cancel = Some(cancel);
+    self
+  }
+
+  /// Gets the reason a search should stop now, if any.
+  pub(crate) fn check(&self) -> Option<Truncation> {
+    if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+      return Some(Truncation::Cancelled);
+    }
+
+    match self.deadline {
+      Some(deadline) if Instant::now() >= deadline => Some(Truncation::Deadline),
+      _ => None,
+    }
+  }
+}
+
+/// The reason a search stopped before it finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Truncation {
+  /// The deadline passed.
+  Deadline,
+  /// The maximum number of results was found.
+  MaxResults,
+  /// The cancellation token was cancelled.
+  Cancelled,
+}
+
+/// The outcome of a limited search.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SearchOutcome {
+  /// The reason the search stopped early, or `None` if it finished.
+  ///
+  /// Paths cut short by [SearchLimits::max_depth] do not truncate a search.
+  pub truncated: Option<Truncation>,
+}
+
+impl SearchOutcome {
+  /// Determines if the search stopped before it finished.
+  pub fn is_truncated(&self) -> bool {
+    self.truncated.is_some()
+  }
+}
@@ -1,68 +1,286 @@
 //! Boggle board search.
 
-use crate::{
-  board::{Board, BoardCell},
-  charset::Charset,
-  trie::{PrefixNodeRef, Prefixes},
+pub mod anagram;
+pub mod batch;
+pub mod iter;
+pub mod limits;
+pub mod options;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod pattern;
+pub mod valid;
+
+use self::{
+  iter::{FindWords, SearchBuffers},
+  limits::SearchOutcome,
+  options::SearchOptions,
 };
-use std::collections::HashSet;
+use crate::{board::Board, charset::Charset, trie::Prefixes};
+
+/// The coordinates of the board cells used to trace a word, in order.
+pub type Path = Vec<(u16, u16)>;
 
-pub fn find_words<'a, 'b: 'a, B: Board<'a>, C: Charset>(
+/// Finds the dictionary words that can be traced on a board.
+///
+/// Word ids are pushed in the order they are found, so words that can be traced more than
+/// once appear more than once. Words found before a search limit is reached are still
+/// pushed, and the outcome tells if the search stopped early. See [FindWords] to search
+/// lazily.
+pub fn find_words<'a, 'b: 'a, B: Board<'a>, C: Charset, M>(
   board: &'b B,
-  prefixes: &Prefixes<C>,
+  prefixes: &Prefixes<C, M>,
+  options: &SearchOptions,
   words: &mut Vec<usize>,
-) {
-  let root = prefixes.root().unwrap();
-
-  for x in 0..board.size() as u16 {
-    for y in 0..board.size() as u16 {
-      let mut path = HashSet::new();
-      path.insert((x, y));
-      find_words_at(board, &root, path, x, y, words);
-    }
+) -> SearchOutcome {
+  let mut found =
+    FindWords::with_buffers(board, prefixes, options, SearchBuffers::default());
+
+  words.extend(&mut found);
+
+  SearchOutcome {
+    truncated: found.truncated(),
   }
 }
 
-fn find_words_at<'a, 'b: 'a, B: Board<'a>, C: Charset>(
-  board: &'b B,
-  node: &PrefixNodeRef<C>,
-  path: HashSet<(u16, u16)>,
-  x: u16,
-  y: u16,
-  words: &mut Vec<usize>,
-) -> Option<()> {
-  let cell = board.at(x, y)?;
-  let node = node.child(cell.ch())?;
+#[cfg(test)]
+mod tests {
+  use super::{
+    find_words,
+    limits::{CancelToken, SearchLimits, Truncation},
+    options::{CellReuse, SearchOptions},
+    pattern::Pattern,
+  };
+  use crate::{
+    board::simple::EnglishAlphaSimpleBoard, charset::english_alpha::EnglishAlpha,
+    trie::EnglishAlphaPrefixes,
+  };
+  use std::time::Instant;
 
-  if let Some(word) = node.word() {
-    words.push(word);
+  /// Finds the distinct words on a board, in alphabetical order.
+  fn search<'a>(
+    board: &EnglishAlphaSimpleBoard,
+    prefixes: &'a EnglishAlphaPrefixes,
+    options: &SearchOptions,
+  ) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    find_words(board, prefixes, options, &mut found);
+
+    let mut found = found
+      .into_iter()
+      .map(|id| prefixes.word(id).unwrap())
+      .collect::<Vec<_>>();
+
+    found.sort_unstable();
+    found.dedup();
+    found
   }
 
-  for neighbor in board.neighbors(x, y) {
-    if path.contains(&(neighbor.x(), neighbor.y())) {
-      continue;
-    }
+  #[test]
+  fn test_find_words() {
+    let words = ["tea", "eat", "ate", "eta", "tee", "axe", "tax", "taxa"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    // t e
+    // a x
+    let board = EnglishAlphaSimpleBoard::new("teax").unwrap();
+    let outcome = find_words(&board, &prefixes, &SearchOptions::new(), &mut Vec::new());
+    assert!(!outcome.is_truncated());
+
+    assert_eq!(
+      search(&board, &prefixes, &SearchOptions::new()),
+      ["ate", "axe", "eat", "eta", "tax", "tea"]
+    );
 
-    let mut neighbor_path = path.clone();
-    neighbor_path.insert((neighbor.x(), neighbor.y()));
-
-    find_words_at(
-      board,
-      &node,
-      neighbor_path,
-      neighbor.x(),
-      neighbor.y(),
-      words,
-    )?;
+    // t e a x
+    let board =
+      EnglishAlphaSimpleBoard::from_faces_with_width(&["t", "e", "a", "x"], 4).unwrap();
+
+    assert_eq!(search(&board, &prefixes, &SearchOptions::new()), ["tea"]);
   }
 
-  Some(())
-}
+  #[test]
+  fn test_find_words_faces() {
+    let words = ["the", "thin", "then", "hen", "tin", "inn"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
 
-#[cfg(test)]
-mod tests {
-  use super::find_words;
-  use crate::{board::simple::EnglishAlphaSimpleBoard, trie::EnglishAlphaPrefixes};
+    // Th e
+    // In n
+    let board = EnglishAlphaSimpleBoard::from_faces(&["Th", "e", "In", "n"]).unwrap();
+    let options = SearchOptions::new().with_max_len(4);
+
+    assert_eq!(
+      search(&board, &prefixes, &options),
+      ["inn", "the", "then", "thin"]
+    );
+  }
+
+  #[test]
+  fn test_find_words_letters() {
+    let words = ["qua", "quit", "quits"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    // Qu a
+    // i  t
+    let board = EnglishAlphaSimpleBoard::from_faces(&["Qu", "a", "i", "t"]).unwrap();
+    let options = SearchOptions::new().with_min_len(3).with_max_len(4);
+
+    // Lengths count letters, so `qua` is three letters and `quit` four.
+    assert_eq!(search(&board, &prefixes, &options), ["qua", "quit"]);
+  }
+
+  #[test]
+  fn test_find_words_options() {
+    let words = ["at", "tea", "eat", "tax", "texa", "tete"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+    let board = EnglishAlphaSimpleBoard::new("teax").unwrap();
+
+    let search = |options: SearchOptions| search(&board, &prefixes, &options);
+
+    assert_eq!(search(SearchOptions::new()), ["eat", "tax", "tea", "texa"]);
+    assert_eq!(
+      search(SearchOptions::new().with_min_len(2).with_max_len(3)),
+      ["at", "eat", "tax", "tea"]
+    );
+    assert_eq!(search(SearchOptions::new().with_min_len(4)), ["texa"]);
+    assert_eq!(
+      search(SearchOptions::new().with_starts(vec![(1, 0)])),
+      ["eat"]
+    );
+    assert_eq!(
+      search(SearchOptions::new().with_reuse(CellReuse::Repeat)),
+      ["eat", "tax", "tea", "tete", "texa"]
+    );
+  }
+
+  #[test]
+  fn test_find_words_constraints() {
+    let words = ["tea", "eat", "ate", "eta", "tax", "axe", "texa"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    // t e
+    // a x
+    let board = EnglishAlphaSimpleBoard::new("teax").unwrap();
+
+    let search = |options: SearchOptions| search(&board, &prefixes, &options);
+
+    assert_eq!(
+      search(SearchOptions::new().with_required(vec![(1, 1)])),
+      ["axe", "tax", "texa"]
+    );
+    assert_eq!(
+      search(SearchOptions::new().with_forbidden(vec![(1, 1)])),
+      ["ate", "eat", "eta", "tea"]
+    );
+    assert_eq!(
+      search(
+        SearchOptions::new()
+          .with_starts(vec![(0, 0)])
+          .with_ends(vec![(0, 1)])
+      ),
+      ["tea", "texa"]
+    );
+    assert_eq!(
+      search(
+        SearchOptions::new()
+          .with_required(vec![(1, 1), (0, 0), (1, 1)])
+          .with_max_len(3)
+      ),
+      ["tax"]
+    );
+    assert!(search(SearchOptions::new().with_required(vec![(5, 5)])).is_empty());
+  }
+
+  #[test]
+  fn test_find_words_pattern() {
+    let words = ["tea", "eat", "ate", "eta", "tax", "axe", "texa", "taxe"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    // t e
+    // a x
+    let board = EnglishAlphaSimpleBoard::new("teax").unwrap();
+
+    let options = |pattern| {
+      let pattern = Pattern::new::<EnglishAlpha>(pattern).unwrap();
+      SearchOptions::new().with_pattern(pattern)
+    };
+
+    assert_eq!(search(&board, &prefixes, &options("?a?")), ["eat", "tax"]);
+    assert_eq!(
+      search(&board, &prefixes, &options("t*")),
+      ["tax", "taxe", "tea", "texa"]
+    );
+    assert_eq!(
+      search(&board, &prefixes, &options("*e")),
+      ["ate", "axe", "taxe"]
+    );
+    assert_eq!(search(&board, &prefixes, &options("t??a")), ["texa"]);
+    assert!(search(&board, &prefixes, &options("e??a")).is_empty());
+
+    // Positions count letters, so `s???t` matches `squat` on a `Qu` face.
+    let prefixes = EnglishAlphaPrefixes::from_words(&["squat", "quat"], 50);
+    let board = EnglishAlphaSimpleBoard::from_faces(&["s", "Qu", "a", "t"]).unwrap();
+
+    assert_eq!(search(&board, &prefixes, &options("s???t")), ["squat"]);
+    assert!(search(&board, &prefixes, &options("s??t")).is_empty());
+    assert_eq!(search(&board, &prefixes, &options("?u*")), ["quat"]);
+  }
+
+  #[test]
+  fn test_find_words_limited() {
+    let words = ["tea", "eat", "tax", "axe", "texa"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+    let board = EnglishAlphaSimpleBoard::new("teax").unwrap();
+
+    let search = |limits: SearchLimits, found: &mut Vec<usize>| {
+      let options = SearchOptions::new().with_limits(limits);
+      find_words(&board, &prefixes, &options, found).truncated
+    };
+
+    let mut found = Vec::new();
+    assert_eq!(search(SearchLimits::new(), &mut found), None);
+    assert_eq!(found.len(), 5);
+
+    let mut found = Vec::new();
+    let limits = SearchLimits::new().with_max_results(2);
+    assert_eq!(search(limits, &mut found), Some(Truncation::MaxResults));
+    assert_eq!(found.len(), 2);
+
+    let mut found = Vec::new();
+    let limits = SearchLimits::new().with_max_results(5);
+    assert_eq!(search(limits, &mut found), None);
+    assert_eq!(found.len(), 5);
+
+    let mut found = Vec::new();
+    let limits = SearchLimits::new().with_max_depth(3);
+    assert_eq!(search(limits, &mut found), None);
+    assert!(!found.contains(&prefixes.get("texa").unwrap()));
+    assert_eq!(found.len(), 4);
+
+    // Th e
+    // In x
+    let prefixes = EnglishAlphaPrefixes::from_words(&["thin", "then"], 50);
+    let board = EnglishAlphaSimpleBoard::from_faces(&["Th", "e", "In", "x"]).unwrap();
+    let options = SearchOptions::new()
+      .with_limits(SearchLimits::new().with_max_depth(2))
+      .with_max_len(4);
+
+    let mut found = Vec::new();
+    find_words(&board, &prefixes, &options, &mut found);
+    assert_eq!(found, [prefixes.get("thin").unwrap()]);
+
+    let mut found = Vec::new();
+    let limits = SearchLimits::new().with_deadline(Instant::now());
+    assert_eq!(search(limits, &mut found), Some(Truncation::Deadline));
+    assert!(found.is_empty());
+
+    let cancel = CancelToken::new();
+    cancel.clone().cancel();
+
+    let mut found = Vec::new();
+    let limits = SearchLimits::new().with_cancel(cancel);
+    assert_eq!(search(limits, &mut found), Some(Truncation::Cancelled));
+    assert!(found.is_empty());
+  }
 
   #[test]
   fn test_4x4() {
@@ -76,10 +294,10 @@ mod tests {
 
     let mut indexes = Vec::new();
 
-    find_words(&board, &prefixes, &mut indexes);
+    find_words(&board, &prefixes, &SearchOptions::new(), &mut indexes);
 
     for i in indexes {
-      println!("word: {}", words[i]);
+      println!("word: {}", prefixes.word(i).unwrap());
     }
   }
 }
@@ -0,0 +1,116 @@
+//! Boggle board search options.
+
+use super::{limits::SearchLimits, pattern::Pattern};
+
+/// How often a board cell may be used by the path of a word.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CellReuse {
+  /// Each cell is used at most once per word, as in the official rules.
+  #[default]
+  Once,
+  /// Cells may be used any number of times, but never twice in a row.
+  Repeat,
+}
+
+/// Options for a board search.
+///
+/// Word lengths count the letters of dictionary words, as scoring does, so `quit` is
+/// four letters even though a `Qu` face is a single charset character.
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+  /// The shortest word found.
+  pub min_len: usize,
+  /// The longest word found, longer paths are not searched.
+  pub max_len: Option<usize>,
+  /// The cells words may start at, in search order, or every cell if `None`.
+  pub starts: Option<Vec<(u16, u16)>>,
+  /// The cells words may end at, or every cell if `None`.
+  pub ends: Option<Vec<(u16, u16)>>,
+  /// The cells every word must use.
+  pub required: Vec<(u16, u16)>,
+  /// The cells no word may use.
+  pub forbidden: Vec<(u16, u16)>,
+  /// The mask every word must match.
+  pub pattern: Option<Pattern>,
+  /// How often a cell may be used by a word.
+  pub reuse: CellReuse,
+  /// Limits on how long the search may run and how much it may find.
+  pub limits: SearchLimits,
+}
+
+impl Default for SearchOptions {
+  fn default() -> Self {
+    Self {
+      min_len: 3,
+      max_len: None,
+      starts: None,
+      ends: None,
+      required: Vec::new(),
+      forbidden: Vec::new(),
+      pattern: None,
+      reuse: CellReuse::default(),
+      limits: SearchLimits::default(),
+    }
+  }
+}
+
+impl SearchOptions {
+  /// Create [SearchOptions] finding words of at least 3 letters from every cell.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Get [SearchOptions] with a minimum word length.
+  pub fn with_min_len(mut self, min_len: usize) -> Self {
+    self.min_len = min_len;
+    self
+  }
+
+  /// Get [SearchOptions] with a maximum word length.
+  pub fn with_max_len(mut self, max_len: usize) -> Self {
+    self.max_len = Some(max_len);
+    self
+  }
+
+  /// Get [SearchOptions] searching from supplied cells, in order.
+  pub fn with_starts(mut self, starts: Vec<(u16, u16)>) -> Self {
+    self.starts = Some(starts);
+    self
+  }
+
+  /// Get [SearchOptions] only finding words ending at supplied cells.
+  pub fn with_ends(mut self, ends: Vec<(u16, u16)>) -> Self {
+    self.ends = Some(ends);
+    self
+  }
+
+  /// Get [SearchOptions] only finding words using every supplied cell.
+  pub fn with_required(mut self, required: Vec<(u16, u16)>) -> Self {
+    self.required = required;
+    self
+  }
+
+  /// Get [SearchOptions] only finding words avoiding every supplied cell.
+  pub fn with_forbidden(mut self, forbidden: Vec<(u16, u16)>) -> Self {
+    self.forbidden = forbidden;
+    self
+  }
+
+  /// Get [SearchOptions] only finding words matching a pattern.
+  pub fn with_pattern(mut self, pattern: Pattern) -> Self {
+    self.pattern = Some(pattern);
+    self
+  }
+
+  /// Get [SearchOptions] with a cell reuse rule.
+  pub fn with_reuse(mut self, reuse: CellReuse) -> Self {
+    self.reuse = reuse;
+    self
+  }
+
+  /// Get [SearchOptions] with search limits.
+  pub fn with_limits(mut self, limits: SearchLimits) -> Self {
+    self.limits = limits;
+    self
+  }
+}
@@ -0,0 +1,148 @@
+//! Parallel boggle board search.
+
+use super::{
+  iter::{all_cells, FindWords, SearchBuffers},
+  limits::{SearchOutcome, Truncation},
+  options::SearchOptions,
+};
+use crate::{board::Board, charset::Charset, trie::Prefixes};
+use rayon::prelude::*;
+
+/// Finds the dictionary words that can be traced on a board, searching from each start
+/// cell on the rayon thread pool.
+///
+/// Unlike [super::find_words] the word ids are sorted and deduplicated, so the result
+/// does not depend on how the work was scheduled. The maximum number of results applies
+/// to the deduplicated words.
+pub fn find_words_parallel<'a, 'b: 'a, B, C, M>(
+  board: &'b B,
+  prefixes: &Prefixes<C, M>,
+  options: &SearchOptions,
+  words: &mut Vec<usize>,
+) -> SearchOutcome
+where
+  B: Board<'a> + Sync,
+  C: Charset,
+  Prefixes<C, M>: Sync,
+{
+  let starts = match &options.starts {
+    Some(starts) => starts.clone(),
+    None => all_cells(board).collect(),
+  };
+
+  // The maximum number of results is applied once the words are deduplicated.
+  let mut unlimited = options.clone();
+  unlimited.limits.max_results = None;
+
+  let (mut found, truncated) = starts
+    .into_par_iter()
+    .fold(
+      || (Vec::new(), None),
+      |(mut words, truncated), start| {
+        let options = unlimited.clone().with_starts(vec![start]);
+        let mut found =
+          FindWords::with_buffers(board, prefixes, &options, SearchBuffers::default());
+
+        words.extend(&mut found);
+        (words, truncated.or(found.truncated()))
+      },
+    )
+    .reduce(
+      || (Vec::new(), None),
+      |(mut a, truncated_a), (mut b, truncated_b)| {
+        a.append(&mut b);
+        (a, truncated_a.or(truncated_b))
+      },
+    );
+
+  found.sort_unstable();
+  found.dedup();
+
+  let mut truncated = truncated;
+  if let Some(max_results) = options.limits.max_results {
+    if found.len() > max_results {
+      found.truncate(max_results);
+      truncated = truncated.or(Some(Truncation::MaxResults));
+    }
+  }
+
+  words.append(&mut found);
+
+  SearchOutcome { truncated }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::find_words_parallel;
+  use crate::{
+    board::simple::EnglishAlphaSimpleBoard,
+    search::{
+      find_words,
+      limits::{SearchLimits, Truncation},
+      options::SearchOptions,
+    },
+    trie::EnglishAlphaPrefixes,
+  };
+
+  #[test]
+  fn test_find_words_parallel() {
+    let words = include_str!(concat!(env!("OUT_DIR"), "/words.txt"))
+      .lines()
+      .collect::<Vec<_>>();
+
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+    let board = include_str!("../../../data/test_grid10x10_1.txt");
+    let board = EnglishAlphaSimpleBoard::new(board.trim()).unwrap();
+
+    let mut expected = Vec::new();
+    find_words(&board, &prefixes, &SearchOptions::new(), &mut expected);
+    expected.sort_unstable();
+    expected.dedup();
+
+    let mut found = Vec::new();
+    let outcome =
+      find_words_parallel(&board, &prefixes, &SearchOptions::new(), &mut found);
+
+    assert!(!found.is_empty());
+    assert!(!outcome.is_truncated());
+    assert_eq!(found, expected);
+  }
+
+  #[test]
+  fn test_find_words_parallel_max_results() {
+    let words = ["see", "set", "tee", "tees", "sees", "eke"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    // s t e
+    // t e e
+    // e e t
+    let board = EnglishAlphaSimpleBoard::new("steteeeet").unwrap();
+
+    let mut all = Vec::new();
+    find_words(&board, &prefixes, &SearchOptions::new(), &mut all);
+    all.sort_unstable();
+    all.dedup();
+    assert!(all.len() > 3);
+
+    for max_results in [3, all.len(), all.len() + 1] {
+      let options = SearchOptions::new()
+        .with_limits(SearchLimits::new().with_max_results(max_results));
+
+      let mut serial = Vec::new();
+      find_words(&board, &prefixes, &options, &mut serial);
+      serial.sort_unstable();
+      serial.dedup();
+
+      let mut found = Vec::new();
+      let outcome = find_words_parallel(&board, &prefixes, &options, &mut found);
+
+      assert_eq!(found.len(), max_results.min(all.len()));
+      assert!(serial.len() <= found.len());
+      assert_eq!(found, all[..found.len()]);
+      assert_eq!(
+        outcome.truncated,
+        (all.len() > max_results).then_some(Truncation::MaxResults)
+      );
+    }
+  }
+}
@@ -0,0 +1,188 @@
+//! Word pattern masks.
+
+use crate::charset::Charset;
+use eyre::Result;
+
+/// The character matching any single character in a pattern string.
+pub const ANY: char = '?';
+
+/// The character matching any run of characters, including none, in a pattern string.
+pub const ANY_RUN: char = '*';
+
+/// A compiled word mask, such as `?a??e`, `re*` or `*ing`.
+///
+/// Positions are letters, as word lengths are, so `s???t` matches `squat`. Letters are
+/// stored as charset characters, with `None` matching any letter. A pattern without
+/// [ANY_RUN] only matches words of its own length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+  head: Vec<Option<u8>>,
+  tail: Option<Vec<Option<u8>>>,
+  /// The letters each charset character stands for, such as `q` and `u` for `q`.
+  letters: Vec<Vec<u8>>,
+}
+
+impl Pattern {
+  /// Create a [Pattern] from a string of letters, [ANY] and at most one [ANY_RUN].
+  pub fn new<C: Charset>(pattern: &str) -> Result<Self> {
+    let mut head = Vec::new();
+    let mut tail: Option<Vec<Option<u8>>> = None;
+
+    for ch in pattern.chars() {
+      let position = match ch {
+        ANY => None,
+        ANY_RUN if tail.is_some() => {
+          eyre::bail!("pattern contains more than one `{ANY_RUN}`")
+        }
+        ANY_RUN => {
+          tail = Some(Vec::new());
+          continue;
+        }
+        ch => Some(
+          C::to_prefix_char(ch)
+            .ok_or(eyre::eyre!("pattern contains invalid character `{ch}`"))?,
+        ),
+      };
+
+      match &mut tail {
+        Some(tail) => tail.push(position),
+        None => head.push(position),
+      }
+    }
+
+    let letters = (0..C::LEN)
+      .map(|ch| {
+        C::to_letters(ch)
+          .unwrap_or_default()
+          .chars()
+          .filter_map(C::to_prefix_char)
+          .collect()
+      })
+      .collect();
+
+    Ok(Self {
+      head,
+      tail,
+      letters,
+    })
+  }
+
+  /// Create a [Pattern] matching words starting with a prefix.
+  pub fn prefix<C: Charset>(prefix: &str) -> Result<Self> {
+    Self::new::<C>(&format!("{prefix}{ANY_RUN}"))
+  }
+
+  /// Create a [Pattern] matching words ending with a suffix.
+  pub fn suffix<C: Charset>(suffix: &str) -> Result<Self> {
+    Self::new::<C>(&format!("{ANY_RUN}{suffix}"))
+  }
+
+  /// Gets the shortest word length the pattern matches.
+  pub fn min_len(&self) -> usize {
+    self.head.len() + self.tail.as_ref().map_or(0, Vec::len)
+  }
+
+  /// Gets the longest word length the pattern matches, or `None` if unbounded.
+  pub fn max_len(&self) -> Option<usize> {
+    match self.tail {
+      Some(_) => None,
+      None => Some(self.head.len()),
+    }
+  }
+
+  /// Gets the letters a charset character stands for.
+  pub fn letters(&self, ch: u8) -> &[u8] {
+    self.letters.get(ch as usize).map_or(&[], Vec::as_slice)
+  }
+
+  /// Determines if a word may have a letter at a position, judging by the fixed start of
+  /// the pattern alone.
+  pub fn allows(&self, position: usize, ch: u8) -> bool {
+    match self.head.get(position) {
+      Some(expected) => expected.is_none_or(|expected| expected == ch),
+      None => self.tail.is_some(),
+    }
+  }
+
+  /// Determines if a word of `len` letters ending with `chars` matches the end of the
+  /// pattern, given every letter was [Pattern::allows]ed.
+  pub fn accepts<I>(&self, len: usize, chars: I) -> bool
+  where
+    I: DoubleEndedIterator<Item = u8>,
+  {
+    if len < self.min_len() || self.max_len().is_some_and(|max_len| len > max_len) {
+      return false;
+    }
+
+    match &self.tail {
+      Some(tail) => tail
+        .iter()
+        .rev()
+        .zip(chars.rev())
+        .all(|(expected, ch)| expected.is_none_or(|expected| expected == ch)),
+      None => true,
+    }
+  }
+
+  /// Determines if a word of prefix characters matches the pattern.
+  pub fn is_match(&self, word: &[u8]) -> bool {
+    let letters = word
+      .iter()
+      .flat_map(|&ch| self.letters(ch))
+      .copied()
+      .collect::<Vec<_>>();
+
+    letters
+      .iter()
+      .enumerate()
+      .all(|(position, &ch)| self.allows(position, ch))
+      && self.accepts(letters.len(), letters.iter().copied())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Pattern;
+  use crate::charset::{english_alpha::EnglishAlpha, Charset};
+
+  fn is_match(pattern: &Pattern, word: &str) -> bool {
+    pattern.is_match(&EnglishAlpha::to_prefix_chars(word).unwrap())
+  }
+
+  #[test]
+  fn test_pattern() {
+    let pattern = Pattern::new::<EnglishAlpha>("?a??e").unwrap();
+    assert!(is_match(&pattern, "table"));
+    assert!(!is_match(&pattern, "tablet"));
+    assert!(!is_match(&pattern, "abide"));
+    assert_eq!((pattern.min_len(), pattern.max_len()), (5, Some(5)));
+
+    let pattern = Pattern::suffix::<EnglishAlpha>("ing").unwrap();
+    assert!(is_match(&pattern, "ring"));
+    assert!(is_match(&pattern, "sing"));
+    assert!(is_match(&pattern, "ing"));
+    assert!(!is_match(&pattern, "singe"));
+
+    let pattern = Pattern::prefix::<EnglishAlpha>("Qu").unwrap();
+    assert!(is_match(&pattern, "quit"));
+    assert!(is_match(&pattern, "quilt"));
+    assert!(!is_match(&pattern, "suit"));
+
+    let pattern = Pattern::new::<EnglishAlpha>("s???t").unwrap();
+    assert!(is_match(&pattern, "squat"));
+    assert!(!is_match(&pattern, "squt"));
+    assert!(is_match(
+      &Pattern::new::<EnglishAlpha>("?u*").unwrap(),
+      "quit"
+    ));
+
+    let pattern = Pattern::new::<EnglishAlpha>("t*?s").unwrap();
+    assert!(is_match(&pattern, "tas"));
+    assert!(is_match(&pattern, "teams"));
+    assert!(!is_match(&pattern, "ts"));
+    assert_eq!((pattern.min_len(), pattern.max_len()), (3, None));
+
+    assert!(Pattern::new::<EnglishAlpha>("a*b*").is_err());
+    assert!(Pattern::new::<EnglishAlpha>("a-b").is_err());
+  }
+}
@@ -1 +1,171 @@
 //! Boggle board word validation.
+
+use super::{iter::all_cells, Path};
+use crate::{
+  board::{Board, BoardCell},
+  charset::Charset,
+};
+
+/// Determines if a word can be traced on a board.
+///
+/// The word does not need to be in a dictionary, only made of charset characters.
+pub fn is_word_on_board<'a, 'b: 'a, C: Charset, B: Board<'a>>(
+  board: &'b B,
+  word: &str,
+) -> bool {
+  let mut paths = Vec::new();
+
+  trace::<C, B>(board, word, true, &mut paths);
+
+  !paths.is_empty()
+}
+
+/// Finds every path that traces a word on a board.
+///
+/// Paths are ordered by starting cell, column by column as
+/// [FindWords](super::iter::FindWords) searches, and then by neighbor order.
+pub fn find_word_paths<'a, 'b: 'a, C: Charset, B: Board<'a>>(
+  board: &'b B,
+  word: &str,
+) -> Vec<Path> {
+  let mut paths = Vec::new();
+
+  trace::<C, B>(board, word, false, &mut paths);
+
+  paths
+}
+
+fn trace<'a, 'b: 'a, C: Charset, B: Board<'a>>(
+  board: &'b B,
+  word: &str,
+  first_only: bool,
+  paths: &mut Vec<Path>,
+) {
+  let word = match C::to_prefix_chars(word) {
+    Some(word) if !word.is_empty() => word,
+    _ => return,
+  };
+
+  let mut visited = vec![false; board.cell_count()];
+  let mut path = Vec::with_capacity(word.len());
+
+  for (x, y) in all_cells(board) {
+    let cell = match board.at(x, y) {
+      Some(cell) => cell,
+      None => continue,
+    };
+
+    trace_at(
+      board,
+      cell,
+      &word,
+      first_only,
+      &mut visited,
+      &mut path,
+      paths,
+    );
+
+    if first_only && !paths.is_empty() {
+      return;
+    }
+  }
+}
+
+fn trace_at<'a, 'b: 'a, B: Board<'a>>(
+  board: &'b B,
+  cell: B::Cell,
+  word: &[u8],
+  first_only: bool,
+  visited: &mut [bool],
+  path: &mut Path,
+  paths: &mut Vec<Path>,
+) {
+  let (x, y) = (cell.x(), cell.y());
+  let index = board.index(x, y);
+
+  if visited[index] || !word.starts_with(cell.face()) {
+    return;
+  }
+
+  let rest = &word[cell.face().len()..];
+
+  path.push((x, y));
+
+  if rest.is_empty() {
+    paths.push(path.clone());
+  } else {
+    visited[index] = true;
+
+    for neighbor in board.neighbors(x, y) {
+      trace_at(board, neighbor, rest, first_only, visited, path, paths);
+
+      if first_only && !paths.is_empty() {
+        break;
+      }
+    }
+
+    visited[index] = false;
+  }
+
+  path.pop();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{find_word_paths, is_word_on_board};
+  use crate::{
+    board::simple::EnglishAlphaSimpleBoard, charset::english_alpha::EnglishAlpha,
+  };
+
+  #[test]
+  fn test_is_word_on_board() {
+    // t e a
+    // s q i
+    // e e t
+    let board = EnglishAlphaSimpleBoard::new("teasqieet").unwrap();
+
+    assert!(is_word_on_board::<EnglishAlpha, _>(&board, "tea"));
+    assert!(is_word_on_board::<EnglishAlpha, _>(&board, "Quit"));
+    assert!(is_word_on_board::<EnglishAlpha, _>(&board, "qeet"));
+    assert!(!is_word_on_board::<EnglishAlpha, _>(&board, "teat"));
+    assert!(!is_word_on_board::<EnglishAlpha, _>(&board, "as"));
+    assert!(!is_word_on_board::<EnglishAlpha, _>(&board, "t3a"));
+    assert!(!is_word_on_board::<EnglishAlpha, _>(&board, ""));
+  }
+
+  #[test]
+  fn test_find_word_paths() {
+    // t e a
+    // s q i
+    // e e t
+    let board = EnglishAlphaSimpleBoard::new("teasqieet").unwrap();
+
+    assert_eq!(
+      find_word_paths::<EnglishAlpha, _>(&board, "set"),
+      [vec![(0, 1), (1, 2), (2, 2)], vec![(0, 1), (1, 0), (0, 0)]]
+    );
+    assert_eq!(
+      find_word_paths::<EnglishAlpha, _>(&board, "tee"),
+      [vec![(2, 2), (1, 2), (0, 2)]]
+    );
+    assert!(find_word_paths::<EnglishAlpha, _>(&board, "teat").is_empty());
+
+    // x a
+    // a x
+    let board = EnglishAlphaSimpleBoard::new("xaax").unwrap();
+    let paths = find_word_paths::<EnglishAlpha, _>(&board, "ax");
+
+    assert_eq!(paths.len(), 4);
+    assert_eq!(paths[0][0], (0, 1));
+
+    // Th e
+    // In x
+    let board = EnglishAlphaSimpleBoard::from_faces(&["Th", "e", "In", "x"]).unwrap();
+
+    assert_eq!(
+      find_word_paths::<EnglishAlpha, _>(&board, "thin"),
+      [vec![(0, 0), (0, 1)]]
+    );
+    assert!(find_word_paths::<EnglishAlpha, _>(&board, "tin").is_empty());
+  }
+}
@@ -3,49 +3,66 @@
 use super::{node::PrefixNode, Prefixes};
 use crate::charset::{Charset, CharsetMap};
 
-impl<C: Charset> Prefixes<C> {
+impl<C: Charset, M> Prefixes<C, M> {
   /// Create a [Prefixes] from supplied words.
   ///
-  /// Ignores words that contain characters not in the supplied charset, with a length
-  /// shorter than 3, or with a length longer than supplied `max_word_len`.
+  /// Ignores words that contain characters not in the supplied charset, are empty, or
+  /// with a length longer than supplied `max_word_len`. Short words are kept, the minimum
+  /// length of a word is chosen when searching.
+  ///
+  /// Words that are equal once special characters are trimmed share a single word id.
+  /// The canonical word table keeps the spelling of the first such word supplied and is
+  /// ordered by trimmed word, so word ids do not depend on the order of `words`.
+  ///
+  /// Every word is given the default metadata payload.
   pub fn from_words<W>(words: &[W], max_word_len: usize) -> Self
   where
-    W: Ord + AsRef<str>,
+    W: AsRef<str>,
+    M: Default,
+  {
+    Self::from_words_with(words, max_word_len, |_, _| M::default())
+  }
+
+  /// Create a [Prefixes] from supplied words and a metadata payload for each word.
+  ///
+  /// Behaves like [Prefixes::from_words], calling `meta` with the index into `words` and
+  /// the spelling of each word kept in the canonical word table.
+  pub fn from_words_with<W, F>(words: &[W], max_word_len: usize, mut meta: F) -> Self
+  where
+    W: AsRef<str>,
+    F: FnMut(usize, &str) -> M,
   {
     let mut prefixes = Self::default();
     let mut trimmed_by_index = words
       .iter()
       .enumerate()
-      .map(|(i, word)| {
-        let word = word.as_ref();
-        let word = C::trim_special_characters(word);
-
-        (i, word)
-      })
+      .filter_map(|(i, word)| Some((i, C::to_prefix_chars(word.as_ref())?)))
+      .filter(|(_, word)| !word.is_empty() && word.len() <= max_word_len)
       .collect::<Vec<_>>();
 
+    // Equal trimmed words are ordered by index so `dedup_by` retains the first spelling.
+    trimmed_by_index.sort_unstable_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
     trimmed_by_index.dedup_by(|a, b| a.1 == b.1);
 
     for (i, word) in trimmed_by_index {
-      if word.len() < 3 || word.len() > max_word_len {
-        continue;
-      }
+      let id = prefixes.words.len();
+      let spelling = words[i].as_ref();
 
-      prefixes.insert_word(i, &word);
+      prefixes.meta.push(meta(i, spelling));
+      prefixes.words.push(spelling.to_string());
+      prefixes.insert_word(id, &word);
     }
 
     prefixes
   }
 
-  /// Inserts a word into the tree.
-  fn insert_word(&mut self, word_index: usize, word: &str) -> Option<()> {
+  /// Inserts a word of prefix characters into the tree.
+  pub(super) fn insert_word(&mut self, word_index: usize, word: &[u8]) {
     // Create a root node if one doesn't exist.
     let mut node = self.get_or_insert_root();
 
     // Iterate over each character.
-    for (i, ch) in word.chars().enumerate() {
-      // Get the character map byte from character.
-      let ch = C::to_prefix_char(ch)?;
+    for (i, &ch) in word.iter().enumerate() {
       // If this is the last character, create a leaf node with the word index, else
       // create a branch node.
       let child = if i == word.len() - 1 {
@@ -56,8 +73,6 @@ impl<C: Charset> Prefixes<C> {
 
       node = self.get_or_insert_child(node, ch, child);
     }
-
-    Some(())
   }
 
   /// Gets or inserts the root branch node.
@@ -112,7 +127,7 @@ impl<C: Charset> Prefixes<C> {
 mod tests {
   use crate::{
     charset::{english_alpha::EnglishAlpha, Charset},
-    trie::EnglishAlphaPrefixes,
+    trie::{EnglishAlphaPrefixes, Prefixes},
   };
 
   #[test]
@@ -155,7 +170,7 @@ mod tests {
 
     'words: for (i, word) in words.iter().enumerate() {
       let trimmed = EnglishAlpha::trim_special_characters(word);
-      if trimmed.len() < 3 || trimmed.len() > 50 {
+      if trimmed.is_empty() || trimmed.len() > 50 {
         continue;
       }
 
@@ -172,17 +187,64 @@ mod tests {
         });
       }
 
-      if node.word() != Some(i) {
-        eprintln!(
-          "Expected leaf node for word `{word}`@{i}, got {:?}",
-          node.word()
-        );
-      }
-      // assert_eq!(
-      //   node.word(),
-      //   Some(i),
-      //   "Expected leaf node for word `{word}`@{i}"
-      // );
+      assert_eq!(
+        node
+          .word()
+          .and_then(|id| prefixes.word(id))
+          .map(EnglishAlpha::trim_special_characters),
+        Some(trimmed),
+        "Expected leaf node for word `{word}`@{i}"
+      );
     }
   }
+
+  #[test]
+  fn test_from_words_canonical() {
+    let words = vec!["tea", "quit", "Quit", "qit", "tea", "at", ""];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    assert_eq!(prefixes.words(), &["at", "quit", "tea"]);
+
+    let root = prefixes.root().unwrap();
+
+    let ch_q = EnglishAlpha::to_prefix_char('q').unwrap();
+    let ch_i = EnglishAlpha::to_prefix_char('i').unwrap();
+    let ch_t = EnglishAlpha::to_prefix_char('t').unwrap();
+
+    let n_q = root.child(ch_q).unwrap();
+    let n_i = n_q.child(ch_i).unwrap();
+    let n_t = n_i.child(ch_t).unwrap();
+
+    assert_eq!(n_t.word(), Some(1));
+    assert_eq!(prefixes.word(1), Some("quit"));
+  }
+
+  #[test]
+  fn test_from_words_with_meta() {
+    let words = vec!["zoo", "cat", "dog", "cat"];
+    let ranks = [3, 1, 2, 4];
+    let prefixes =
+      Prefixes::<EnglishAlpha, u32>::from_words_with(&words, 50, |i, _| ranks[i]);
+
+    assert_eq!(prefixes.words(), &["cat", "dog", "zoo"]);
+    assert_eq!(prefixes.metas(), &[1, 2, 3]);
+
+    let root = prefixes.root().unwrap();
+    let n_d = root
+      .child(EnglishAlpha::to_prefix_char('d').unwrap())
+      .unwrap();
+    let n_o = n_d
+      .child(EnglishAlpha::to_prefix_char('o').unwrap())
+      .unwrap();
+    let n_g = n_o
+      .child(EnglishAlpha::to_prefix_char('g').unwrap())
+      .unwrap();
+
+    assert_eq!(n_g.meta(), Some(&2));
+    assert_eq!(n_o.meta(), None);
+
+    let prefixes = prefixes.map_meta(|_, word, rank| (word.len(), rank * 10));
+
+    assert_eq!(prefixes.meta(2), Some(&(3, 30)));
+  }
 }
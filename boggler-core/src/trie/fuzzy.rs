@@ -0,0 +1,122 @@
+//! Prefix tree fuzzy lookup.
+
+use super::{PrefixNodeRef, Prefixes};
+use crate::charset::Charset;
+
+/// A word found by a fuzzy lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FuzzyMatch {
+  /// The edit distance between the word and the looked up word.
+  pub distance: usize,
+  /// The word id.
+  pub word: usize,
+}
+
+impl<C: Charset, M> Prefixes<C, M> {
+  /// Gets the words within a Levenshtein distance of `max_distance` from a word, ranked
+  /// by distance and then word id.
+  ///
+  /// Distances count insertions, deletions and substitutions of prefix characters, so a
+  /// `qu` in either word counts as a single character. Characters not in the charset
+  /// never match.
+  pub fn fuzzy(&self, word: &str, max_distance: usize) -> Vec<FuzzyMatch> {
+    let mut matches = Vec::new();
+    let root = match self.root() {
+      Some(root) => root,
+      None => return matches,
+    };
+
+    let word = C::trim_special_characters(word)
+      .chars()
+      .map(|ch| C::to_prefix_char(ch).unwrap_or(u8::MAX))
+      .collect::<Vec<_>>();
+
+    // The first row is the distance from the empty prefix to each prefix of the word.
+    let row = (0..=word.len()).collect::<Vec<_>>();
+
+    for (ch, child) in root.children() {
+      fuzzy_at(&child, ch, &word, &row, max_distance, &mut matches);
+    }
+
+    matches.sort_unstable();
+    matches
+  }
+}
+
+/// Computes the row of edit distances of a node from the row of its parent, recording the
+/// node word if it is close enough and descending while any distance may still be.
+fn fuzzy_at<C: Charset, M>(
+  node: &PrefixNodeRef<C, M>,
+  ch: u8,
+  word: &[u8],
+  parent_row: &[usize],
+  max_distance: usize,
+  matches: &mut Vec<FuzzyMatch>,
+) {
+  let mut row = Vec::with_capacity(parent_row.len());
+
+  row.push(parent_row[0] + 1);
+
+  for i in 1..parent_row.len() {
+    let insert = row[i - 1] + 1;
+    let delete = parent_row[i] + 1;
+    let replace = parent_row[i - 1] + usize::from(word[i - 1] != ch);
+
+    row.push(insert.min(delete).min(replace));
+  }
+
+  let distance = row[row.len() - 1];
+  if distance <= max_distance {
+    if let Some(word) = node.word() {
+      matches.push(FuzzyMatch { distance, word });
+    }
+  }
+
+  if row.iter().any(|distance| *distance <= max_distance) {
+    for (ch, child) in node.children() {
+      fuzzy_at(&child, ch, word, &row, max_distance, matches);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::FuzzyMatch;
+  use crate::trie::EnglishAlphaPrefixes;
+
+  #[test]
+  fn test_fuzzy() {
+    let words = ["cat", "cart", "cast", "coat", "dog", "act", "scat", "quit"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    let found = prefixes
+      .fuzzy("cat", 1)
+      .into_iter()
+      .map(|found| (found.distance, prefixes.word(found.word).unwrap()))
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      found,
+      [
+        (0, "cat"),
+        (1, "cart"),
+        (1, "cast"),
+        (1, "coat"),
+        (1, "scat")
+      ]
+    );
+
+    assert_eq!(
+      prefixes.fuzzy("act", 0),
+      [FuzzyMatch {
+        distance: 0,
+        word: prefixes.get("act").unwrap(),
+      }]
+    );
+    assert_eq!(
+      prefixes.fuzzy("qiut", 2)[0].word,
+      prefixes.get("quit").unwrap()
+    );
+    assert!(prefixes.fuzzy("zzzzzz", 2).is_empty());
+  }
+}
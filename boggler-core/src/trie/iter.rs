@@ -0,0 +1,180 @@
+//! Prefix tree lookup and iteration.
+
+use super::{PrefixNodeRef, Prefixes};
+use crate::charset::Charset;
+
+impl<C: Charset, M> Prefixes<C, M> {
+  /// Gets the word id of a word.
+  pub fn get(&self, word: &str) -> Option<usize> {
+    self.get_prefix(word)?.word()
+  }
+
+  /// Gets the node reached by following the characters of a prefix from the root.
+  pub fn get_prefix(&self, prefix: &str) -> Option<PrefixNodeRef<'_, C, M>> {
+    self.root()?.descend(&C::to_prefix_chars(prefix)?)
+  }
+
+  /// Gets an iterator over the ids of every word in the tree in lexical order.
+  pub fn iter(&self) -> PrefixWords<'_, C, M> {
+    match self.root() {
+      Some(root) => root.words(),
+      None => PrefixWords::empty(self),
+    }
+  }
+
+  /// Gets the number of words in the tree.
+  pub fn count_words(&self) -> usize {
+    self.iter().count()
+  }
+}
+
+impl<'prefixes, C: Charset, M> PrefixNodeRef<'prefixes, C, M> {
+  /// Gets the index of the node in the arena.
+  pub fn index(&self) -> usize {
+    self.node
+  }
+
+  /// Gets the node reached by following supplied prefix characters from this node.
+  pub fn descend(&self, chars: &[u8]) -> Option<Self> {
+    chars.iter().try_fold(*self, |node, &ch| node.child(ch))
+  }
+
+  /// Gets an iterator over the prefix character and node reference of each child, in
+  /// prefix character order.
+  pub fn children(&self) -> PrefixChildren<'prefixes, C, M> {
+    PrefixChildren { node: *self, ch: 0 }
+  }
+
+  /// Gets an iterator over the ids of the words at and below this node in lexical
+  /// order.
+  pub fn words(&self) -> PrefixWords<'prefixes, C, M> {
+    PrefixWords {
+      prefixes: self.prefixes,
+      stack: vec![self.node],
+    }
+  }
+
+  /// Gets the number of words at and below this node.
+  pub fn count_words(&self) -> usize {
+    self.words().count()
+  }
+}
+
+/// An iterator over the children of a prefix node.
+#[derive(Debug)]
+pub struct PrefixChildren<'prefixes, C: Charset, M = ()> {
+  node: PrefixNodeRef<'prefixes, C, M>,
+  ch: u8,
+}
+
+impl<'prefixes, C: Charset, M> Iterator for PrefixChildren<'prefixes, C, M> {
+  type Item = (u8, PrefixNodeRef<'prefixes, C, M>);
+
+  fn next(&mut self) -> Option<Self::Item> {
+    while self.ch < C::LEN {
+      let ch = self.ch;
+
+      self.ch += 1;
+
+      if let Some(child) = self.node.child(ch) {
+        return Some((ch, child));
+      }
+    }
+
+    None
+  }
+}
+
+/// A lexically ordered iterator over the word ids below a prefix node.
+#[derive(Debug)]
+pub struct PrefixWords<'prefixes, C: Charset, M = ()> {
+  prefixes: &'prefixes Prefixes<C, M>,
+  stack: Vec<usize>,
+}
+
+impl<'prefixes, C: Charset, M> PrefixWords<'prefixes, C, M> {
+  /// Create a [PrefixWords] iterator yielding no words.
+  fn empty(prefixes: &'prefixes Prefixes<C, M>) -> Self {
+    Self {
+      prefixes,
+      stack: Vec::new(),
+    }
+  }
+}
+
+impl<'prefixes, C: Charset, M> Iterator for PrefixWords<'prefixes, C, M> {
+  type Item = usize;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    while let Some(node) = self.stack.pop() {
+      let node = &self.prefixes.arena[node];
+
+      self
+        .stack
+        .extend(node.children_rev().map(|(_, child)| child));
+
+      if let Some(word) = node.word() {
+        return Some(word);
+      }
+    }
+
+    None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    charset::{english_alpha::EnglishAlpha, Charset},
+    trie::EnglishAlphaPrefixes,
+  };
+
+  #[test]
+  fn test_get() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&["tea", "team", "quit"], 50);
+
+    assert_eq!(prefixes.get("team"), Some(2));
+    assert_eq!(prefixes.get("QUIT"), Some(0));
+    assert_eq!(prefixes.get("te"), None);
+    assert_eq!(prefixes.get("toe"), None);
+    assert!(prefixes.get_prefix("te").is_some());
+    assert!(prefixes.get_prefix("to").is_none());
+  }
+
+  #[test]
+  fn test_children() {
+    let prefixes = EnglishAlphaPrefixes::from_words(&["tea", "ten", "toe", "zoo"], 50);
+
+    let children = prefixes
+      .get_prefix("te")
+      .unwrap()
+      .children()
+      .map(|(ch, _)| EnglishAlpha::from_prefix_char(ch).unwrap())
+      .collect::<String>();
+
+    assert_eq!(children, "an");
+  }
+
+  #[test]
+  fn test_words() {
+    let words = ["teams", "ten", "tea", "zoo", "team", "toe"];
+    let prefixes = EnglishAlphaPrefixes::from_words(&words, 50);
+
+    let all = prefixes
+      .iter()
+      .map(|id| prefixes.word(id).unwrap())
+      .collect::<Vec<_>>();
+
+    assert_eq!(all, ["tea", "team", "teams", "ten", "toe", "zoo"]);
+
+    let tea = prefixes.get_prefix("tea").unwrap();
+    let completions = tea
+      .words()
+      .map(|id| prefixes.word(id).unwrap())
+      .collect::<Vec<_>>();
+
+    assert_eq!(completions, ["tea", "team", "teams"]);
+    assert_eq!(tea.count_words(), 3);
+    assert_eq!(prefixes.count_words(), 6);
+  }
+}
@@ -1,8 +1,12 @@
 //! Word prefix tree.
 
 pub mod from_words;
+pub mod fuzzy;
+pub mod iter;
+pub mod mutate;
 pub mod node;
 pub mod serialize;
+pub mod set_ops;
 
 use self::node::PrefixNode;
 use crate::charset::{english_alpha::EnglishAlpha, Charset};
@@ -11,15 +15,34 @@ use crate::charset::{english_alpha::EnglishAlpha, Charset};
 pub type EnglishAlphaPrefixes = Prefixes<EnglishAlpha>;
 
 /// A word prefix tree.
-#[derive(Debug, Default)]
-pub struct Prefixes<C: Charset> {
+///
+/// The tree owns a canonical word table and the word of every leaf node is an index, or
+/// word id, into that table.
+///
+/// Each word id also has a metadata payload of type `M`, such as a frequency rank or a
+/// point value, stored in a side table alongside the canonical word table.
+#[derive(Debug)]
+pub struct Prefixes<C: Charset, M = ()> {
   root: Option<usize>,
   arena: Vec<PrefixNode<C>>,
+  words: Vec<String>,
+  meta: Vec<M>,
 }
 
-impl<C: Charset> Prefixes<C> {
+impl<C: Charset, M> Default for Prefixes<C, M> {
+  fn default() -> Self {
+    Self {
+      root: None,
+      arena: Vec::new(),
+      words: Vec::new(),
+      meta: Vec::new(),
+    }
+  }
+}
+
+impl<C: Charset, M> Prefixes<C, M> {
   /// Gets the root node.
-  pub fn root(&self) -> Option<PrefixNodeRef<C>> {
+  pub fn root(&self) -> Option<PrefixNodeRef<C, M>> {
     Some(PrefixNodeRef::new(self.root?, self))
   }
 
@@ -33,6 +56,54 @@ impl<C: Charset> Prefixes<C> {
     &self.arena
   }
 
+  /// Gets the canonical spelling of a word id.
+  pub fn word(&self, id: usize) -> Option<&str> {
+    self.words.get(id).map(String::as_str)
+  }
+
+  /// Gets the canonical word table indexed by word id.
+  pub fn words(&self) -> &[String] {
+    &self.words
+  }
+
+  /// Gets the metadata payload of a word id.
+  pub fn meta(&self, id: usize) -> Option<&M> {
+    self.meta.get(id)
+  }
+
+  /// Gets a mutable reference to the metadata payload of a word id.
+  pub fn meta_mut(&mut self, id: usize) -> Option<&mut M> {
+    self.meta.get_mut(id)
+  }
+
+  /// Gets the metadata table indexed by word id.
+  pub fn metas(&self) -> &[M] {
+    &self.meta
+  }
+
+  /// Get a [Prefixes] with the metadata payload of each word replaced.
+  ///
+  /// The mapping function receives the word id, canonical spelling and current payload.
+  pub fn map_meta<N, F>(self, mut f: F) -> Prefixes<C, N>
+  where
+    F: FnMut(usize, &str, M) -> N,
+  {
+    let meta = self
+      .meta
+      .into_iter()
+      .zip(&self.words)
+      .enumerate()
+      .map(|(id, (meta, word))| f(id, word, meta))
+      .collect();
+
+    Prefixes {
+      root: self.root,
+      arena: self.arena,
+      words: self.words,
+      meta,
+    }
+  }
+
   /// Get a [Prefixes] with a root index.
   pub fn with_root(mut self, root: usize) -> Self {
     self.root = Some(root);
@@ -44,26 +115,51 @@ impl<C: Charset> Prefixes<C> {
     self.arena = arena;
     self
   }
+
+  /// Get a [Prefixes] with a canonical word table.
+  pub fn with_words(mut self, words: Vec<String>) -> Self {
+    self.words = words;
+    self
+  }
+
+  /// Get a [Prefixes] with a metadata table.
+  pub fn with_meta(mut self, meta: Vec<M>) -> Self {
+    self.meta = meta;
+    self
+  }
 }
 
 /// A prefix node reference.
-#[derive(Debug, Clone, Copy)]
-pub struct PrefixNodeRef<'prefixes, C: Charset> {
+#[derive(Debug)]
+pub struct PrefixNodeRef<'prefixes, C: Charset, M = ()> {
   node: usize,
-  prefixes: &'prefixes Prefixes<C>,
+  prefixes: &'prefixes Prefixes<C, M>,
+}
+
+impl<'prefixes, C: Charset, M> Clone for PrefixNodeRef<'prefixes, C, M> {
+  fn clone(&self) -> Self {
+    *self
+  }
 }
 
-impl<'prefixes, C: Charset> PrefixNodeRef<'prefixes, C> {
+impl<'prefixes, C: Charset, M> Copy for PrefixNodeRef<'prefixes, C, M> {}
+
+impl<'prefixes, C: Charset, M> PrefixNodeRef<'prefixes, C, M> {
   /// Create a [PrefixNodeRef] from node index and [Prefixes].
-  pub fn new(node: usize, prefixes: &'prefixes Prefixes<C>) -> Self {
+  pub fn new(node: usize, prefixes: &'prefixes Prefixes<C, M>) -> Self {
     Self { node, prefixes }
   }
 
-  /// Gets node word.
+  /// Gets node word id.
   pub fn word(&self) -> Option<usize> {
     self.prefixes.arena[self.node].word()
   }
 
+  /// Gets node word metadata payload.
+  pub fn meta(&self) -> Option<&'prefixes M> {
+    self.prefixes.meta(self.word()?)
+  }
+
   /// Gets child node reference.
   pub fn child(&self, ch: u8) -> Option<Self> {
     Some(Self::new(
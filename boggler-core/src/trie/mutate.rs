@@ -0,0 +1,185 @@
+//! Prefix tree insertion and removal.
+
+use super::{node::PrefixNode, Prefixes};
+use crate::charset::{Charset, CharsetMap};
+
+impl<C: Charset, M> Prefixes<C, M> {
+  /// Determines if the tree contains a word.
+  pub fn contains(&self, word: &str) -> bool {
+    self.get(word).is_some()
+  }
+
+  /// Inserts a word with the default metadata payload, returning its word id.
+  ///
+  /// See [Prefixes::insert_with].
+  pub fn insert(&mut self, word: &str) -> Option<usize>
+  where
+    M: Default,
+  {
+    self.insert_with(word, M::default())
+  }
+
+  /// Inserts a word with a metadata payload, returning its word id.
+  ///
+  /// If the tree already contains the word the id of the existing word is returned and
+  /// its spelling and payload are left untouched. Returns `None` if the word is empty or
+  /// contains characters not in the charset.
+  ///
+  /// Inserted words are appended to the canonical word table, so unlike
+  /// [Prefixes::from_words] word ids are not in lexical order.
+  pub fn insert_with(&mut self, word: &str, meta: M) -> Option<usize> {
+    let trimmed = C::to_prefix_chars(word).filter(|word| !word.is_empty())?;
+
+    if let Some(id) = self.find_word(&trimmed) {
+      return Some(id);
+    }
+
+    let id = self.words.len();
+
+    self.words.push(word.to_string());
+    self.meta.push(meta);
+    self.insert_word(id, &trimmed);
+
+    Some(id)
+  }
+
+  /// Removes a word, returning the removed word id.
+  ///
+  /// Branches left without words are pruned from the tree, although their nodes remain
+  /// in the arena until [Prefixes::compact] is called. The canonical word table and
+  /// metadata table keep the removed entry so other word ids are unaffected, and word
+  /// ids are never reused.
+  pub fn remove(&mut self, word: &str) -> Option<usize> {
+    let word = C::to_prefix_chars(word)?;
+    let mut path = Vec::with_capacity(word.len() + 1);
+    let mut node = self.root?;
+
+    path.push(node);
+
+    for &ch in &word {
+      node = self.arena[node].child(ch)?;
+      path.push(node);
+    }
+
+    let id = self.arena[node].word()?;
+
+    self.arena[node].make_branch();
+
+    // Walk back towards the root, unlinking branches without children.
+    for (i, &ch) in word.iter().enumerate().rev() {
+      let node = &self.arena[path[i + 1]];
+      if node.is_leaf() || node.has_children() {
+        break;
+      }
+
+      self.arena[path[i]].children_mut().remove(ch);
+    }
+
+    Some(id)
+  }
+
+  /// Removes nodes no longer reachable from the root from the arena.
+  ///
+  /// Node indices are reassigned in depth first, prefix character order.
+  pub fn compact(&mut self) {
+    let root = match self.root {
+      Some(root) => root,
+      None => return,
+    };
+
+    let mut arena: Vec<PrefixNode<C>> = Vec::with_capacity(self.arena.len());
+    let mut stack = vec![(root, None)];
+
+    while let Some((old, parent)) = stack.pop() {
+      let new = arena.len();
+      let node = std::mem::replace(&mut self.arena[old], PrefixNode::branch());
+
+      for (ch, child) in node.children_rev() {
+        stack.push((child, Some((new, ch))));
+      }
+
+      if let Some((parent, ch)) = parent {
+        arena[parent].children_mut().insert(ch, new);
+      }
+
+      arena.push(node);
+    }
+
+    self.root = Some(0);
+    self.arena = arena;
+  }
+
+  /// Gets the word id of a word of prefix characters.
+  pub(super) fn find_word(&self, word: &[u8]) -> Option<usize> {
+    let mut node = self.root?;
+
+    for &ch in word {
+      node = self.arena[node].child(ch)?;
+    }
+
+    self.arena[node].word()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    charset::english_alpha::EnglishAlpha,
+    trie::{EnglishAlphaPrefixes, Prefixes},
+  };
+
+  #[test]
+  fn test_insert() {
+    let mut prefixes = EnglishAlphaPrefixes::from_words(&["tea", "team"], 50);
+
+    assert!(!prefixes.contains("te"));
+    assert_eq!(prefixes.insert("te"), Some(2));
+    assert_eq!(prefixes.insert("TE"), Some(2));
+    assert_eq!(prefixes.insert("te5"), None);
+    assert_eq!(prefixes.insert(""), None);
+
+    assert!(prefixes.contains("te"));
+    assert!(prefixes.contains("tea"));
+    assert!(prefixes.contains("team"));
+    assert_eq!(prefixes.word(2), Some("te"));
+  }
+
+  #[test]
+  fn test_remove() {
+    let words = ["tea", "team", "teams", "ten"];
+    let mut prefixes =
+      Prefixes::<EnglishAlpha, usize>::from_words_with(&words, 50, |i, _| i * 10);
+
+    assert_eq!(prefixes.remove("teams"), Some(2));
+    assert_eq!(prefixes.remove("teams"), None);
+    assert_eq!(prefixes.remove("te"), None);
+    assert!(!prefixes.contains("teams"));
+    assert!(prefixes.contains("team"));
+
+    assert_eq!(prefixes.remove("tea"), Some(0));
+    assert!(!prefixes.contains("tea"));
+    assert!(prefixes.contains("team"));
+    assert_eq!(prefixes.meta(1), Some(&10));
+
+    assert_eq!(prefixes.remove("team"), Some(1));
+    assert_eq!(prefixes.remove("ten"), Some(3));
+    assert!(!prefixes.arena()[prefixes.root_index().unwrap()].has_children());
+  }
+
+  #[test]
+  fn test_compact() {
+    let mut prefixes = EnglishAlphaPrefixes::from_words(&["tea", "team", "zoo"], 50);
+    let len = prefixes.arena().len();
+
+    prefixes.remove("team");
+    prefixes.remove("zoo");
+    prefixes.insert("ten");
+    prefixes.compact();
+
+    assert_eq!(prefixes.arena().len(), len - 4 + 1);
+    assert!(prefixes.contains("tea"));
+    assert!(prefixes.contains("ten"));
+    assert!(!prefixes.contains("team"));
+    assert!(!prefixes.contains("zoo"));
+  }
+}
@@ -41,6 +41,26 @@ impl<C: Charset> PrefixNode<C> {
     self.children().get(ch)
   }
 
+  /// Gets an iterator over the prefix character and index of each child of node, in
+  /// prefix character order.
+  pub fn children_iter(&self) -> impl Iterator<Item = (u8, usize)> + '_ {
+    (0..C::LEN).filter_map(move |ch| Some((ch, self.child(ch)?)))
+  }
+
+  /// Gets an iterator over the prefix character and index of each child of node, in
+  /// reverse prefix character order so that a stack they are pushed onto pops them in
+  /// prefix character order.
+  pub fn children_rev(&self) -> impl Iterator<Item = (u8, usize)> + '_ {
+    (0..C::LEN)
+      .rev()
+      .filter_map(move |ch| Some((ch, self.child(ch)?)))
+  }
+
+  /// Determines if node has children.
+  pub fn has_children(&self) -> bool {
+    self.children_iter().next().is_some()
+  }
+
   /// Gets children of node.
   pub fn children(&self) -> &C::PrefixCharMap {
     match self {
@@ -64,6 +84,17 @@ impl<C: Charset> PrefixNode<C> {
       Self::Branch(_) => None,
     }
   }
+
+  /// Converts a leaf node into a branch node, keeping its children.
+  pub fn make_branch(&mut self) {
+    if let Self::Leaf(leaf) = self {
+      let mut branch = PrefixBranch::<C>::default();
+
+      branch.children.copy_from(&leaf.children);
+
+      *self = Self::Branch(branch);
+    }
+  }
 }
 
 /// A word prefix leaf node.
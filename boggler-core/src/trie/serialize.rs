@@ -9,7 +9,7 @@ pub trait Codegen {
   fn codegen<W: Write>(&self, f: &mut W) -> std::io::Result<()>;
 }
 
-impl<C: Charset> Codegen for Prefixes<C> {
+impl<C: Charset, M: Codegen> Codegen for Prefixes<C, M> {
   fn codegen<W: Write>(&self, f: &mut W) -> std::io::Result<()> {
     write!(f, "{{")?;
     write!(f, "Prefixes::default()");
@@ -25,6 +25,19 @@ impl<C: Charset> Codegen for Prefixes<C> {
     }
     write!(f, "])");
 
+    write!(f, ".with_words(vec![")?;
+    for word in self.words() {
+      write!(f, "{word:?}.to_string(),")?;
+    }
+    write!(f, "])")?;
+
+    write!(f, ".with_meta(vec![")?;
+    for meta in self.metas() {
+      meta.codegen(f)?;
+      write!(f, ",")?;
+    }
+    write!(f, "])")?;
+
     write!(f, "}}")
   }
 }
@@ -65,3 +78,9 @@ impl<C: Charset> Codegen for PrefixBranch<C> {
     write!(f, "}}")
   }
 }
+
+impl Codegen for () {
+  fn codegen<W: Write>(&self, f: &mut W) -> std::io::Result<()> {
+    write!(f, "()")
+  }
+}
@@ -0,0 +1,220 @@
+//! Prefix tree set operations.
+
+use super::Prefixes;
+use crate::charset::Charset;
+
+/// The word ids a word produced by a set operation had in the source trees.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WordSources {
+  /// The word id in the tree the operation was called on.
+  pub left: Option<usize>,
+  /// The word id in the tree supplied to the operation.
+  pub right: Option<usize>,
+}
+
+/// A prefix tree produced by a set operation.
+#[derive(Debug)]
+pub struct Combined<C: Charset, M = ()> {
+  /// The resulting prefix tree.
+  pub prefixes: Prefixes<C, M>,
+  /// The source word ids indexed by word id of the resulting tree.
+  pub sources: Vec<WordSources>,
+}
+
+/// A set operation.
+#[derive(Debug, Clone, Copy)]
+enum SetOp {
+  Union,
+  Intersection,
+  Difference,
+}
+
+impl SetOp {
+  /// Determines if the words below a node present in either tree can be kept.
+  fn descend(self, left: bool, right: bool) -> bool {
+    match self {
+      Self::Union => left || right,
+      Self::Intersection => left && right,
+      Self::Difference => left,
+    }
+  }
+
+  /// Determines if a word present in either tree is kept.
+  fn keep(self, left: bool, right: bool) -> bool {
+    match self {
+      Self::Union => left || right,
+      Self::Intersection => left && right,
+      Self::Difference => left && !right,
+    }
+  }
+}
+
+impl<C: Charset, M: Clone> Prefixes<C, M> {
+  /// Gets a tree containing the words in either tree.
+  ///
+  /// Words in both trees take their spelling and metadata payload from `self`.
+  pub fn union(&self, other: &Prefixes<C, M>) -> Combined<C, M> {
+    self.combine(other, SetOp::Union, |left, right| match left {
+      Some(left) => (self.words[left].clone(), self.meta[left].clone()),
+      None => {
+        let right = right.expect("union word is in neither tree");
+        (other.words[right].clone(), other.meta[right].clone())
+      }
+    })
+  }
+
+  /// Gets a tree containing the words in both trees.
+  ///
+  /// Words take their spelling and metadata payload from `self`.
+  pub fn intersection<N>(&self, other: &Prefixes<C, N>) -> Combined<C, M> {
+    self.combine(other, SetOp::Intersection, |left, _| self.entry(left))
+  }
+
+  /// Gets a tree containing the words in `self` that are not in `other`.
+  pub fn difference<N>(&self, other: &Prefixes<C, N>) -> Combined<C, M> {
+    self.combine(other, SetOp::Difference, |left, _| self.entry(left))
+  }
+
+  /// Gets the spelling and metadata payload of a word id in `self`.
+  fn entry(&self, id: Option<usize>) -> (String, M) {
+    let id = id.expect("word is not in left tree");
+    (self.words[id].clone(), self.meta[id].clone())
+  }
+
+  /// Walks both trees together, building a tree of the words kept by `op`.
+  fn combine<N, F>(
+    &self,
+    other: &Prefixes<C, N>,
+    op: SetOp,
+    mut entry: F,
+  ) -> Combined<C, M>
+  where
+    F: FnMut(Option<usize>, Option<usize>) -> (String, M),
+  {
+    let mut found = Vec::new();
+    let mut path = Vec::new();
+
+    walk(
+      self, other, self.root, other.root, op, &mut path, &mut found,
+    );
+
+    let mut prefixes = Prefixes::default();
+    let mut sources = Vec::with_capacity(found.len());
+
+    // Words are found in prefix character order so word ids stay in lexical order.
+    for (word, left, right) in found {
+      let id = prefixes.words.len();
+      let (spelling, meta) = entry(left, right);
+
+      prefixes.words.push(spelling);
+      prefixes.meta.push(meta);
+      prefixes.insert_word(id, &word);
+      sources.push(WordSources { left, right });
+    }
+
+    Combined { prefixes, sources }
+  }
+}
+
+/// Walks the nodes of two trees at the same prefix, collecting the prefix characters and
+/// source word ids of each word kept by `op`.
+fn walk<C: Charset, L, R>(
+  left: &Prefixes<C, L>,
+  right: &Prefixes<C, R>,
+  left_node: Option<usize>,
+  right_node: Option<usize>,
+  op: SetOp,
+  path: &mut Vec<u8>,
+  found: &mut Vec<(Vec<u8>, Option<usize>, Option<usize>)>,
+) {
+  if !op.descend(left_node.is_some(), right_node.is_some()) {
+    return;
+  }
+
+  let left_node = left_node.map(|node| &left.arena[node]);
+  let right_node = right_node.map(|node| &right.arena[node]);
+  let left_word = left_node.and_then(|node| node.word());
+  let right_word = right_node.and_then(|node| node.word());
+
+  if !path.is_empty() && op.keep(left_word.is_some(), right_word.is_some()) {
+    found.push((path.clone(), left_word, right_word));
+  }
+
+  for ch in 0..C::LEN {
+    let left_child = left_node.and_then(|node| node.child(ch));
+    let right_child = right_node.and_then(|node| node.child(ch));
+
+    path.push(ch);
+    walk(left, right, left_child, right_child, op, path, found);
+    path.pop();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::WordSources;
+  use crate::{
+    charset::english_alpha::EnglishAlpha,
+    trie::{EnglishAlphaPrefixes, Prefixes},
+  };
+
+  fn sources(left: Option<usize>, right: Option<usize>) -> WordSources {
+    WordSources { left, right }
+  }
+
+  #[test]
+  fn test_union() {
+    let base = Prefixes::<EnglishAlpha, u32>::from_words_with(
+      &["tea", "ten", "zoo"],
+      50,
+      |i, _| i as u32,
+    );
+    let regional = Prefixes::<EnglishAlpha, u32>::from_words_with(
+      &["Tea", "team", "arvo"],
+      50,
+      |i, _| 10 + i as u32,
+    );
+
+    let union = base.union(&regional);
+
+    assert_eq!(
+      union.prefixes.words(),
+      &["arvo", "tea", "team", "ten", "zoo"]
+    );
+    assert_eq!(union.prefixes.metas(), &[12, 0, 11, 1, 2]);
+    assert_eq!(union.sources[0], sources(None, Some(0)));
+    assert_eq!(union.sources[1], sources(Some(0), Some(1)));
+    assert_eq!(union.sources[3], sources(Some(1), None));
+    assert!(union.prefixes.contains("team"));
+  }
+
+  #[test]
+  fn test_intersection() {
+    let base = EnglishAlphaPrefixes::from_words(&["tea", "team", "ten"], 50);
+    let other = EnglishAlphaPrefixes::from_words(&["team", "ten", "tent"], 50);
+
+    let intersection = base.intersection(&other);
+
+    assert_eq!(intersection.prefixes.words(), &["team", "ten"]);
+    assert_eq!(
+      intersection.sources,
+      [sources(Some(1), Some(0)), sources(Some(2), Some(1))]
+    );
+    assert!(!intersection.prefixes.contains("tea"));
+    assert!(!intersection.prefixes.contains("tent"));
+  }
+
+  #[test]
+  fn test_difference() {
+    let base = EnglishAlphaPrefixes::from_words(&["tea", "team", "ten", "zoo"], 50);
+    let mut bans = EnglishAlphaPrefixes::from_words(&["team", "zoo", "zebra"], 50);
+
+    bans.remove("zoo");
+
+    let difference = base.difference(&bans);
+
+    assert_eq!(difference.prefixes.words(), &["tea", "ten", "zoo"]);
+    assert_eq!(difference.sources[2], sources(Some(3), None));
+    assert!(!difference.prefixes.contains("team"));
+  }
+}
@@ -0,0 +1,134 @@
+//! Word frequency lists.
+
+use boggler_core::trie::serialize::Codegen;
+use std::{
+  collections::HashMap,
+  fs::File,
+  io::{self, BufRead, BufReader, Write},
+  path::Path,
+};
+
+/// How common a word is, from `0.0` for words missing from a frequency list up to `1.0`
+/// for the most frequent word in the list.
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Commonness(pub f32);
+
+impl Commonness {
+  /// The commonness at and above which a word is considered common.
+  ///
+  /// Corresponds to the most frequent half of a frequency list.
+  pub const COMMON: Self = Self(0.5);
+}
+
+impl Codegen for Commonness {
+  fn codegen<W: Write>(&self, f: &mut W) -> io::Result<()> {
+    write!(f, "Commonness({:?})", self.0)
+  }
+}
+
+/// A word frequency list.
+#[derive(Debug, Default, Clone)]
+pub struct Frequencies {
+  ranks: HashMap<String, usize>,
+}
+
+impl Frequencies {
+  /// Reads a frequency list from a file.
+  ///
+  /// See [Frequencies::from_reader] for the expected format.
+  pub fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+    Self::from_reader(BufReader::new(File::open(path)?))
+  }
+
+  /// Reads a frequency list delimited by newline characters.
+  ///
+  /// Each line is either a word, in which case words are ranked by line order with the
+  /// most frequent first, or a word followed by whitespace and an occurrence count, in
+  /// which case words are ranked by descending count and then by line order.
+  pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+    let mut counts = Vec::new();
+
+    for line in reader.lines() {
+      let line = line?;
+      let mut columns = line.split_whitespace();
+      let word = match columns.next() {
+        Some(word) => word.to_lowercase(),
+        None => continue,
+      };
+
+      let count = match columns.next() {
+        Some(count) => Some(count.parse::<u64>().map_err(|err| {
+          io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid count `{count}` for word `{word}`: {err}"),
+          )
+        })?),
+        None => None,
+      };
+
+      counts.push((word, count));
+    }
+
+    if counts.iter().any(|(_, count)| count.is_some()) {
+      counts.sort_by_key(|(_, count)| std::cmp::Reverse(count.unwrap_or(0)));
+    }
+
+    let mut ranks = HashMap::with_capacity(counts.len());
+    for (word, _) in counts {
+      let rank = ranks.len();
+      ranks.entry(word).or_insert(rank);
+    }
+
+    Ok(Self { ranks })
+  }
+
+  /// Gets the number of words in the list.
+  pub fn len(&self) -> usize {
+    self.ranks.len()
+  }
+
+  /// Determines if the list is empty.
+  pub fn is_empty(&self) -> bool {
+    self.ranks.is_empty()
+  }
+
+  /// Gets the zero based rank of a word, where `0` is the most frequent.
+  pub fn rank(&self, word: &str) -> Option<usize> {
+    self.ranks.get(&word.to_lowercase()).copied()
+  }
+
+  /// Gets the commonness of a word.
+  pub fn commonness(&self, word: &str) -> Commonness {
+    match self.rank(word) {
+      Some(rank) => Commonness(1.0 - rank as f32 / self.len() as f32),
+      None => Commonness::default(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Commonness, Frequencies};
+
+  #[test]
+  fn test_from_reader_ranked() {
+    let frequencies = Frequencies::from_reader("the\nCat\n\nsat\n".as_bytes()).unwrap();
+
+    assert_eq!(frequencies.len(), 3);
+    assert_eq!(frequencies.rank("cat"), Some(1));
+    assert_eq!(frequencies.commonness("the"), Commonness(1.0));
+    assert_eq!(frequencies.commonness("tnf"), Commonness(0.0));
+  }
+
+  #[test]
+  fn test_from_reader_counts() {
+    let frequencies =
+      Frequencies::from_reader("sat 10\nthe 500\ncat 20\nmat 20".as_bytes()).unwrap();
+
+    assert_eq!(frequencies.rank("the"), Some(0));
+    assert_eq!(frequencies.rank("cat"), Some(1));
+    assert_eq!(frequencies.rank("mat"), Some(2));
+    assert_eq!(frequencies.rank("sat"), Some(3));
+    assert!(Frequencies::from_reader("the many".as_bytes()).is_err());
+  }
+}
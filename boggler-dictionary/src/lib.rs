@@ -1,23 +1,77 @@
-use once_cell::unsync::OnceCell;
+pub mod frequency;
+
+use self::frequency::{Commonness, Frequencies};
 use boggler_core::{
   charset::{english_alpha::EnglishAlpha, Charset},
   trie::Prefixes,
 };
+use once_cell::unsync::OnceCell;
+
+/// The longest word kept in a dictionary.
+const MAX_WORD_LEN: usize = 50;
 
 pub struct Dictionary<C: Charset + 'static> {
-  words: &'static Vec<&'static str>,
-  prefixes: &'static Prefixes<C>,
+  prefixes: Prefixes<C, Commonness>,
 }
 
 impl<C: Charset + 'static> Dictionary<C> {
+  /// Create a [Dictionary] from supplied words and an optional frequency list.
+  ///
+  /// Words missing from the frequency list, or every word when no list is supplied, have
+  /// the default [Commonness].
+  pub fn from_words<W: AsRef<str>>(
+    words: &[W],
+    frequencies: Option<&Frequencies>,
+  ) -> Self {
+    let prefixes = Prefixes::from_words_with(words, MAX_WORD_LEN, |_, word| {
+      frequencies
+        .map(|frequencies| frequencies.commonness(word))
+        .unwrap_or_default()
+    });
+
+    Self { prefixes }
+  }
+
+  /// Get a [Dictionary] with the commonness of each word taken from a frequency list.
+  pub fn with_frequencies(self, frequencies: &Frequencies) -> Self {
+    Self {
+      prefixes: self
+        .prefixes
+        .map_meta(|_, word, _| frequencies.commonness(word)),
+    }
+  }
+
   /// Gets words in dictionary.
-  pub fn words(&self) -> &[&str] {
-    self.words
+  pub fn words(&self) -> &[String] {
+    self.prefixes.words()
   }
 
   /// Gets prefix trie of dictionary.
-  pub fn prefixes(&self) -> &Prefixes<C> {
-    self.prefixes
+  pub fn prefixes(&self) -> &Prefixes<C, Commonness> {
+    &self.prefixes
+  }
+
+  /// Gets the commonness of a word id.
+  pub fn commonness(&self, id: usize) -> Commonness {
+    self.prefixes.meta(id).copied().unwrap_or_default()
+  }
+
+  /// Sorts word ids from most to least common, breaking ties by word id.
+  pub fn sort_by_commonness(&self, ids: &mut [usize]) {
+    ids.sort_by(|a, b| {
+      self
+        .commonness(*b)
+        .0
+        .total_cmp(&self.commonness(*a).0)
+        .then(a.cmp(b))
+    });
+  }
+
+  /// Removes word ids less common than `min`.
+  ///
+  /// Passing [Commonness::COMMON] keeps common words only.
+  pub fn retain_common(&self, ids: &mut Vec<usize>, min: Commonness) {
+    ids.retain(|id| self.commonness(*id) >= min);
   }
 }
 
@@ -45,7 +99,11 @@ impl Dictionary<EnglishAlpha> {
 
 #[cfg(test)]
 mod tests {
-  use crate::Dictionary;
+  use crate::{
+    frequency::{Commonness, Frequencies},
+    Dictionary,
+  };
+  use boggler_core::charset::english_alpha::EnglishAlpha;
   use std::time::Instant;
 
   #[test]
@@ -55,6 +113,29 @@ mod tests {
 
     println!("done: {:?}", time.elapsed());
     println!("words: {}", dictionary.words().len());
-    println!("prefixes: {:?}", dictionary.prefixes().arena.len());
+    println!("prefixes: {:?}", dictionary.prefixes().arena().len());
+  }
+
+  #[test]
+  fn test_commonness() {
+    let words = vec!["tea", "eat", "ate", "eta", "tae"];
+    let frequencies =
+      Frequencies::from_reader("eat\nate\ntea\nthe\n".as_bytes()).unwrap();
+    let dictionary = Dictionary::<EnglishAlpha>::from_words(&words, Some(&frequencies));
+
+    let mut ids = (0..dictionary.words().len()).collect::<Vec<_>>();
+
+    dictionary.sort_by_commonness(&mut ids);
+
+    let found = ids.iter().map(|id| dictionary.words()[*id].as_str());
+    assert_eq!(
+      found.collect::<Vec<_>>(),
+      ["eat", "ate", "tea", "eta", "tae"]
+    );
+
+    dictionary.retain_common(&mut ids, Commonness::COMMON);
+
+    let found = ids.iter().map(|id| dictionary.words()[*id].as_str());
+    assert_eq!(found.collect::<Vec<_>>(), ["eat", "ate", "tea"]);
   }
 }